@@ -1,10 +1,14 @@
 use inkwell::builder::Builder;
 use inkwell::context::Context;
 use inkwell::module::Module;
+use inkwell::targets::TargetData;
+use inkwell::values::{FunctionValue, IntValue};
+use inkwell::{FloatPredicate, IntPredicate};
 
 use std::path;
 
-use crate::emitter::environment::Environment;
+use crate::emitter::environment::{Environment, FunctionVariable, Value, Variable};
+use crate::emitter::types::CType;
 use crate::parser::node::Node;
 
 pub struct Emitter {
@@ -12,17 +16,26 @@ pub struct Emitter {
     pub builder: Builder,
     pub module: Module,
     pub environment: Environment,
+    pub target_data: TargetData,
+    // the declared return type of the function being emitted
+    pub return_type: Option<CType>,
 }
 impl Emitter {
     pub fn new() -> Emitter {
         let context = Context::create();
         let builder = context.create_builder();
         let module = context.create_module("my_module");
+        // x86-64 System V data layout, which the module is compiled for and
+        // `sizeof` is computed with
+        let target_data = TargetData::create("e-m:e-i64:64-f80:128-n8:16:32:64-S128");
+        module.set_data_layout(&target_data.get_data_layout());
         Emitter {
             context,
             builder,
             module,
             environment: Environment::new(),
+            target_data,
+            return_type: None,
         }
     }
     pub fn print_to_file(&self) {
@@ -31,4 +44,213 @@ impl Emitter {
     pub fn emit(&mut self, node: Node) {
         node.emit(self)
     }
+    // the function `name` of type `ctype`, recorded when it is first
+    // declared; later declarations have to agree with it, and only one of them
+    // may be a `definition`. A declaration without a `prototype`, as in
+    // `int f();`, leaves the parameters open, so it only has to agree on the
+    // return type
+    pub fn declare_function(
+        &mut self,
+        name: &str,
+        ctype: CType,
+        prototype: bool,
+        definition: bool,
+    ) {
+        let function_variable = match self.environment.get(&name.to_string()) {
+            Some(Variable::Function(function_variable)) => {
+                let compatible = if function_variable.prototype && prototype {
+                    function_variable.ctype == ctype
+                } else {
+                    function_variable.ctype.get_return_type() == ctype.get_return_type()
+                };
+                if !compatible {
+                    panic!(
+                        "error: conflicting types for \'{}\': \'{}\' and \'{}\'",
+                        name, function_variable.ctype, ctype
+                    );
+                }
+                if function_variable.defined && definition {
+                    panic!("error: redefinition of \'{}\'", name);
+                }
+                let defined = function_variable.defined || definition;
+                if function_variable.prototype || !prototype {
+                    FunctionVariable {
+                        defined,
+                        ..function_variable
+                    }
+                } else {
+                    // the parameters are fixed by the first prototype, which
+                    // has to agree with the calls made without it
+                    if let Some(function) = self.module.get_function(name) {
+                        if function.get_type() != ctype.to_llvm_function_type(self) {
+                            panic!(
+                                "error: conflicting types for \'{}\': \'{}\' and \'{}\'",
+                                name, function_variable.ctype, ctype
+                            );
+                        }
+                    }
+                    FunctionVariable {
+                        ctype,
+                        prototype,
+                        defined,
+                        ..function_variable
+                    }
+                }
+            }
+            Some(_) => panic!("error: \'{}\' redeclared as different kind of symbol", name),
+            None => FunctionVariable {
+                name: name.to_string(),
+                ctype,
+                prototype,
+                defined: definition,
+            },
+        };
+        self.environment
+            .update(name.to_string(), Variable::Function(function_variable));
+    }
+    // the function `name` of type `ctype` in the module, which is added once it
+    // is first called or defined
+    pub fn get_function_value(&self, name: &str, ctype: &CType) -> FunctionValue {
+        let function_type = ctype.to_llvm_function_type(self);
+        match self.module.get_function(name) {
+            // a call without a prototype may have fixed other parameters already
+            Some(function) if function.get_type() != function_type => {
+                panic!("error: conflicting types for \'{}\': \'{}\'", name, ctype)
+            }
+            Some(function) => function,
+            None => self.module.add_function(name, function_type, None),
+        }
+    }
+    // the function the builder is currently positioned in
+    pub fn get_function(&self) -> FunctionValue {
+        match self.builder.get_insert_block() {
+            Some(block) => block.get_parent().expect("basic block without a function"),
+            None => panic!("builder is not positioned in a function"),
+        }
+    }
+    // C truth value: an `i1` that is set when `value` compares unequal to zero
+    pub fn emit_condition(&self, value: Value) -> IntValue {
+        let value = match value {
+            Value::Int(value) => value,
+            Value::Float(value) => {
+                let zero = value.get_type().const_float(0.0);
+                return self.builder.build_float_compare(
+                    FloatPredicate::UNE,
+                    value,
+                    zero,
+                    "condition",
+                );
+            }
+            Value::Pointer(pointer) => {
+                let address_type = CType::SIZE_T.to_llvm_type(self).into_int_type();
+                self.builder
+                    .build_ptr_to_int(pointer, address_type, "condition")
+            }
+            Value::Null => panic!("error: void value is not a valid condition"),
+        };
+        let zero = value.get_type().const_int(0, false);
+        self.builder
+            .build_int_compare(IntPredicate::NE, value, zero, "condition")
+    }
+    // conversion to `_Bool`: 1 when `value` compares unequal to zero, 0
+    // otherwise, as in a condition
+    pub fn emit_bool(&self, value: Value) -> IntValue {
+        let condition = self.emit_condition(value);
+        let bool_type = CType::BOOL.to_llvm_type(self).into_int_type();
+        self.builder
+            .build_int_z_extend(condition, bool_type, "bool")
+    }
+    // convert `value` from type `from` to type `to`
+    // an array subscript or pointer offset of type `ctype`, widened to 64 bits
+    // first because a GEP treats its indices as signed
+    pub fn emit_index(&self, value: Value, ctype: &CType) -> IntValue {
+        if !ctype.is_integer() {
+            panic!("error: array subscript is not an integer");
+        }
+        match self
+            .emit_conversion(value, ctype, &CType::PTRDIFF_T)
+            .get_int()
+        {
+            Ok(value) => value,
+            Err(msg) => panic!("{}", msg),
+        }
+    }
+    pub fn emit_conversion(&self, value: Value, from: &CType, to: &CType) -> Value {
+        if from == to {
+            return value;
+        }
+        // a cast to `void` discards the value
+        if to.is_void() {
+            return Value::Null;
+        }
+        if *to == CType::BOOL {
+            return Value::Int(self.emit_bool(value));
+        }
+        match (value, to) {
+            (Value::Int(value), CType::Integer { .. }) => {
+                let target = to.to_llvm_type(self).into_int_type();
+                let width = value.get_type().get_bit_width();
+                let target_width = target.get_bit_width();
+                let value = if target_width > width {
+                    // widening keeps the value of the source type
+                    if from.is_signed() {
+                        self.builder.build_int_s_extend(value, target, "conversion")
+                    } else {
+                        self.builder.build_int_z_extend(value, target, "conversion")
+                    }
+                } else if target_width < width {
+                    self.builder.build_int_truncate(value, target, "conversion")
+                } else {
+                    value
+                };
+                Value::Int(value)
+            }
+            (Value::Int(value), CType::Floating(_)) => {
+                let target = to.to_llvm_type(self).into_float_type();
+                Value::Float(if from.is_signed() {
+                    self.builder
+                        .build_signed_int_to_float(value, target, "conversion")
+                } else {
+                    self.builder
+                        .build_unsigned_int_to_float(value, target, "conversion")
+                })
+            }
+            // the fractional part is discarded
+            (Value::Float(value), CType::Integer { .. }) => {
+                let target = to.to_llvm_type(self).into_int_type();
+                Value::Int(if to.is_signed() {
+                    self.builder
+                        .build_float_to_signed_int(value, target, "conversion")
+                } else {
+                    self.builder
+                        .build_float_to_unsigned_int(value, target, "conversion")
+                })
+            }
+            (Value::Float(value), CType::Floating(_)) => {
+                let target = to.to_llvm_type(self).into_float_type();
+                Value::Float(if *to == CType::DOUBLE {
+                    self.builder.build_float_ext(value, target, "conversion")
+                } else {
+                    self.builder.build_float_trunc(value, target, "conversion")
+                })
+            }
+            (Value::Int(value), CType::Pointer(_)) => {
+                let target = to.to_llvm_type(self).into_pointer_type();
+                Value::Pointer(self.builder.build_int_to_ptr(value, target, "conversion"))
+            }
+            (Value::Pointer(pointer), CType::Integer { .. }) => {
+                let target = to.to_llvm_type(self).into_int_type();
+                Value::Int(self.builder.build_ptr_to_int(pointer, target, "conversion"))
+            }
+            (Value::Pointer(pointer), CType::Pointer(_)) => {
+                let target = to.to_llvm_type(self).into_pointer_type();
+                Value::Pointer(
+                    self.builder
+                        .build_pointer_cast(pointer, target, "conversion"),
+                )
+            }
+            (Value::Null, _) => panic!("error: void value not ignored as it ought to be"),
+            _ => panic!("error: cannot convert {:?} to {:?}", from, to),
+        }
+    }
 }
@@ -1,12 +1,27 @@
-use inkwell::values::{IntValue, PointerValue};
+use inkwell::values::{BasicValueEnum, FloatValue, IntValue, PointerValue};
+
+use crate::emitter::types::CType;
 
 pub struct Environment {
     variables: Vec<(String, Variable)>,
+    // the number of variables declared outside of each open scope
+    scopes: Vec<usize>,
 }
 impl Environment {
     pub fn new() -> Environment {
         let variables: Vec<(String, Variable)> = Vec::new();
-        Environment { variables }
+        Environment {
+            variables,
+            scopes: Vec::new(),
+        }
+    }
+    pub fn push_scope(&mut self) {
+        self.scopes.push(self.variables.len());
+    }
+    // forget every variable declared since the matching `push_scope`
+    pub fn pop_scope(&mut self) {
+        let len = self.scopes.pop().expect("no scope to pop");
+        self.variables.truncate(len);
     }
     pub fn get(&self, skey: &String) -> Option<Variable> {
         match self.variables.iter().rev().find(|x| &x.0 == skey) {
@@ -14,8 +29,20 @@ impl Environment {
             None => None,
         }
     }
+    // a variable declared in the innermost scope, which a new declaration of
+    // the same name would redefine rather than shadow
+    pub fn get_local(&self, skey: &String) -> Option<Variable> {
+        self.find(skey).map(|idx| self.variables[idx].1.clone())
+    }
+    // only a variable of the innermost scope is found, so that an inner
+    // declaration shadows an outer one instead of replacing it
     fn find(&self, skey: &String) -> Option<usize> {
-        match self.variables.iter().rev().position(|x| &x.0 == skey) {
+        let start = self.scopes.last().cloned().unwrap_or(0);
+        match self.variables[start..]
+            .iter()
+            .rev()
+            .position(|x| &x.0 == skey)
+        {
             Some(idx) => Some(self.variables.len() - idx - 1),
             None => None,
         }
@@ -31,39 +58,131 @@ impl Environment {
 #[derive(Debug, PartialEq, Clone)]
 pub enum Variable {
     Int(IntVariable),
+    Float(FloatVariable),
+    Pointer(PointerVariable),
     Array(ArrayVariable),
+    Function(FunctionVariable),
     Null,
 }
 
+impl Variable {
+    // a variable of type `ctype` stored at `pointer`
+    pub fn new(name: String, pointer: PointerValue, ctype: CType) -> Variable {
+        match ctype {
+            CType::Integer { .. } => Variable::Int(IntVariable {
+                name,
+                pointer,
+                ctype,
+            }),
+            CType::Floating(_) => Variable::Float(FloatVariable {
+                name,
+                pointer,
+                ctype,
+            }),
+            CType::Pointer(_) => Variable::Pointer(PointerVariable {
+                name,
+                pointer,
+                ctype,
+            }),
+            CType::Array(_, _) => Variable::Array(ArrayVariable {
+                name,
+                pointer,
+                ctype,
+            }),
+            CType::Void => panic!("error: variable \'{}\' has incomplete type \'void\'", name),
+            CType::Function(_, _) => panic!("error: \'{}\' declared as a function", name),
+        }
+    }
+    pub fn get_type(&self) -> CType {
+        match self {
+            Variable::Int(int_variable) => int_variable.ctype.clone(),
+            Variable::Float(float_variable) => float_variable.ctype.clone(),
+            Variable::Pointer(pointer_variable) => pointer_variable.ctype.clone(),
+            Variable::Array(array_variable) => array_variable.ctype.clone(),
+            Variable::Function(function_variable) => function_variable.ctype.clone(),
+            Variable::Null => panic!("error: variable has no type"),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct IntVariable {
     pub name: String,
     pub pointer: PointerValue,
+    pub ctype: CType,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct FloatVariable {
+    pub name: String,
+    pub pointer: PointerValue,
+    pub ctype: CType,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct PointerVariable {
+    pub name: String,
+    pub pointer: PointerValue,
+    pub ctype: CType,
 }
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct ArrayVariable {
     pub name: String,
     pub pointer: PointerValue,
+    pub ctype: CType,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct FunctionVariable {
+    pub name: String,
+    pub ctype: CType,
+    // whether the parameters have been declared, which `int f();` does not do
+    pub prototype: bool,
+    // whether a body has been emitted, as opposed to only declarations
+    pub defined: bool,
 }
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum Value {
     Int(IntValue),
+    Float(FloatValue),
     Pointer(PointerValue),
     Null,
 }
 impl Value {
+    pub fn from_basic_value(value: BasicValueEnum) -> Value {
+        match value {
+            BasicValueEnum::IntValue(value) => Value::Int(value),
+            BasicValueEnum::FloatValue(value) => Value::Float(value),
+            BasicValueEnum::PointerValue(pointer) => Value::Pointer(pointer),
+            _ => panic!("unsupported value {:?}", value),
+        }
+    }
     pub fn get_pointer(self) -> Result<PointerValue, String> {
         match self {
             Value::Pointer(pointer) => Ok(pointer),
             _ => Err("this is not a pointer value".to_string()),
         }
     }
+    pub fn get_basic_value(self) -> Result<BasicValueEnum, String> {
+        match self {
+            Value::Int(value) => Ok(value.into()),
+            Value::Float(value) => Ok(value.into()),
+            Value::Pointer(pointer) => Ok(pointer.into()),
+            Value::Null => Err("this is not a value".to_string()),
+        }
+    }
     pub fn get_int(self) -> Result<IntValue, String> {
         match self {
             Value::Int(value) => Ok(value),
             _ => Err("this is not a value value".to_string()),
         }
     }
+    pub fn get_float(self) -> Result<FloatValue, String> {
+        match self {
+            Value::Float(value) => Ok(value),
+            _ => Err("this is not a floating value".to_string()),
+        }
+    }
 }
@@ -1,2 +1,3 @@
 pub mod emitter;
 pub mod environment;
+pub mod types;
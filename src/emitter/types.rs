@@ -0,0 +1,335 @@
+use inkwell::types::{ArrayType, BasicTypeEnum, FunctionType, PointerType};
+use inkwell::AddressSpace;
+
+use std::fmt;
+
+use crate::emitter::emitter::Emitter;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum IntegerKind {
+    Bool,
+    Char,
+    Short,
+    Int,
+    Long,
+    LongLong,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum FloatingKind {
+    Float,
+    Double,
+}
+
+// the C type of an object or an expression
+#[derive(Debug, PartialEq, Clone)]
+pub enum CType {
+    Void,
+    Integer { kind: IntegerKind, signed: bool },
+    Floating(FloatingKind),
+    Pointer(Box<CType>),
+    Array(Box<CType>, u32),
+    // return type and parameter types
+    Function(Box<CType>, Vec<CType>),
+}
+impl CType {
+    pub const BOOL: CType = CType::Integer {
+        kind: IntegerKind::Bool,
+        signed: false,
+    };
+    // plain `char` is signed, as on x86-64
+    pub const CHAR: CType = CType::Integer {
+        kind: IntegerKind::Char,
+        signed: true,
+    };
+    pub const INT: CType = CType::Integer {
+        kind: IntegerKind::Int,
+        signed: true,
+    };
+    pub const UNSIGNED_INT: CType = CType::Integer {
+        kind: IntegerKind::Int,
+        signed: false,
+    };
+    pub const LONG: CType = CType::Integer {
+        kind: IntegerKind::Long,
+        signed: true,
+    };
+    pub const UNSIGNED_LONG: CType = CType::Integer {
+        kind: IntegerKind::Long,
+        signed: false,
+    };
+    pub const FLOAT: CType = CType::Floating(FloatingKind::Float);
+    pub const DOUBLE: CType = CType::Floating(FloatingKind::Double);
+    // the type of `sizeof`
+    pub const SIZE_T: CType = CType::UNSIGNED_LONG;
+    // the type of the difference of two pointers
+    pub const PTRDIFF_T: CType = CType::LONG;
+    pub fn is_integer(&self) -> bool {
+        matches!(self, CType::Integer { .. })
+    }
+    pub fn is_floating(&self) -> bool {
+        matches!(self, CType::Floating(_))
+    }
+    pub fn is_arithmetic(&self) -> bool {
+        self.is_integer() || self.is_floating()
+    }
+    pub fn is_pointer(&self) -> bool {
+        matches!(self, CType::Pointer(_))
+    }
+    pub fn is_void(&self) -> bool {
+        matches!(self, CType::Void)
+    }
+    pub fn is_array(&self) -> bool {
+        matches!(self, CType::Array(_, _))
+    }
+    pub fn is_signed(&self) -> bool {
+        match self {
+            CType::Integer { signed, .. } => *signed,
+            _ => false,
+        }
+    }
+    // the type of an element reached through one level of `[]` or `*`
+    pub fn get_element_type(&self) -> CType {
+        match self {
+            CType::Pointer(element) | CType::Array(element, _) => *element.clone(),
+            _ => panic!("error: subscripted value is not an array or pointer"),
+        }
+    }
+    pub fn get_return_type(&self) -> CType {
+        match self {
+            CType::Function(return_type, _) => *return_type.clone(),
+            _ => panic!("error: {:?} is not a function type", self),
+        }
+    }
+    // an array used as a value, or declared as a parameter, becomes a pointer
+    // to its first element
+    pub fn decay(&self) -> CType {
+        match self {
+            CType::Array(element, _) => CType::Pointer(element.clone()),
+            ctype => ctype.clone(),
+        }
+    }
+    fn rank(&self) -> u32 {
+        match self {
+            CType::Integer { kind, .. } => match kind {
+                IntegerKind::Bool => 0,
+                IntegerKind::Char => 1,
+                IntegerKind::Short => 2,
+                IntegerKind::Int => 3,
+                IntegerKind::Long => 4,
+                IntegerKind::LongLong => 5,
+            },
+            CType::Void => panic!("error: void value not ignored as it ought to be"),
+            _ => panic!("error: {:?} is not an arithmetic type", self),
+        }
+    }
+    fn bit_width(&self) -> u32 {
+        match self {
+            CType::Integer { kind, .. } => match kind {
+                IntegerKind::Bool | IntegerKind::Char => 8,
+                IntegerKind::Short => 16,
+                IntegerKind::Int => 32,
+                IntegerKind::Long | IntegerKind::LongLong => 64,
+            },
+            _ => panic!("error: {:?} is not an integer type", self),
+        }
+    }
+    // the unsigned type of the same rank
+    fn to_unsigned(&self) -> CType {
+        match self {
+            CType::Integer { kind, .. } => CType::Integer {
+                kind: kind.clone(),
+                signed: false,
+            },
+            _ => panic!("error: {:?} is not an integer type", self),
+        }
+    }
+    // integer promotion: arithmetic is never done in a type narrower than `int`
+    pub fn promote(&self) -> CType {
+        if self.is_floating() {
+            return self.clone();
+        }
+        if self.rank() < CType::INT.rank() {
+            return CType::INT;
+        }
+        self.clone()
+    }
+    // default argument promotion, for an argument with no parameter type to
+    // convert to: `float` becomes `double` on top of integer promotion
+    pub fn promote_argument(&self) -> CType {
+        match self {
+            CType::Integer { .. } | CType::Void => self.promote(),
+            ctype if *ctype == CType::FLOAT => CType::DOUBLE,
+            ctype => ctype.clone(),
+        }
+    }
+    // the common type of the operands of an arithmetic operator
+    pub fn usual_arithmetic_conversion(&self, other: &CType) -> CType {
+        // a floating operand wins over any integer one
+        if self.is_floating() || other.is_floating() {
+            if *self == CType::DOUBLE || *other == CType::DOUBLE {
+                return CType::DOUBLE;
+            }
+            return CType::FLOAT;
+        }
+        let (lhs, rhs) = (self.promote(), other.promote());
+        let (higher, lower) = if lhs.rank() >= rhs.rank() {
+            (lhs, rhs)
+        } else {
+            (rhs, lhs)
+        };
+        if higher.is_signed() && !lower.is_signed() {
+            // the signed type is kept only when it can represent every value
+            // of the unsigned one
+            if higher.rank() > lower.rank() && higher.bit_width() > lower.bit_width() {
+                return higher;
+            }
+            return higher.to_unsigned();
+        }
+        higher
+    }
+    // the value `value` takes when converted to this integer type, as in a
+    // constant expression
+    pub fn convert_constant(&self, value: i64, emitter: &Emitter) -> i64 {
+        if *self == CType::BOOL {
+            return (value != 0) as i64;
+        }
+        let bits = self.size_of(emitter) * 8;
+        if bits >= 64 {
+            return value;
+        }
+        let value = value & ((1 << bits) - 1);
+        if self.is_signed() && value >> (bits - 1) == 1 {
+            return value - (1 << bits);
+        }
+        value
+    }
+    pub fn to_llvm_type(&self, emitter: &Emitter) -> BasicTypeEnum {
+        match self {
+            CType::Integer { kind, .. } => match kind {
+                // a `_Bool` object is a byte holding 0 or 1
+                IntegerKind::Bool | IntegerKind::Char => emitter.context.i8_type().into(),
+                IntegerKind::Short => emitter.context.i16_type().into(),
+                IntegerKind::Int => emitter.context.i32_type().into(),
+                IntegerKind::Long | IntegerKind::LongLong => emitter.context.i64_type().into(),
+            },
+            CType::Floating(kind) => match kind {
+                FloatingKind::Float => emitter.context.f32_type().into(),
+                FloatingKind::Double => emitter.context.f64_type().into(),
+            },
+            // `void *` is lowered like `char *`
+            CType::Pointer(element) if element.is_void() => {
+                pointer_type(emitter.context.i8_type().into()).into()
+            }
+            CType::Pointer(element) => pointer_type(element.to_llvm_type(emitter)).into(),
+            CType::Array(element, size) => array_type(element.to_llvm_type(emitter), *size).into(),
+            CType::Void => panic!("error: incomplete type \'void\' is not an object type"),
+            CType::Function(_, _) => panic!("error: function type is not an object type"),
+        }
+    }
+    pub fn to_llvm_function_type(&self, emitter: &Emitter) -> FunctionType {
+        match self {
+            CType::Function(return_type, parameters) => {
+                let parameters: Vec<BasicTypeEnum> = parameters
+                    .iter()
+                    .map(|parameter| parameter.to_llvm_type(emitter))
+                    .collect();
+                if return_type.is_void() {
+                    return emitter.context.void_type().fn_type(&parameters, false);
+                }
+                match return_type.to_llvm_type(emitter) {
+                    BasicTypeEnum::ArrayType(ty) => ty.fn_type(&parameters, false),
+                    BasicTypeEnum::FloatType(ty) => ty.fn_type(&parameters, false),
+                    BasicTypeEnum::IntType(ty) => ty.fn_type(&parameters, false),
+                    BasicTypeEnum::PointerType(ty) => ty.fn_type(&parameters, false),
+                    BasicTypeEnum::StructType(ty) => ty.fn_type(&parameters, false),
+                    BasicTypeEnum::VectorType(ty) => ty.fn_type(&parameters, false),
+                }
+            }
+            _ => panic!("error: {:?} is not a function type", self),
+        }
+    }
+    // the size in bytes under the target data layout
+    pub fn size_of(&self, emitter: &Emitter) -> u64 {
+        emitter
+            .target_data
+            .get_abi_size(&self.to_llvm_type(emitter))
+    }
+}
+
+// the C spelling of a type, as in `unsigned long` or `char *`
+impl fmt::Display for CType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CType::Void => write!(f, "void"),
+            CType::Integer { kind, signed } => {
+                let name = match kind {
+                    IntegerKind::Bool => return write!(f, "_Bool"),
+                    IntegerKind::Char => "char",
+                    IntegerKind::Short => "short",
+                    IntegerKind::Int => "int",
+                    IntegerKind::Long => "long",
+                    IntegerKind::LongLong => "long long",
+                };
+                if *signed {
+                    write!(f, "{}", name)
+                } else {
+                    write!(f, "unsigned {}", name)
+                }
+            }
+            CType::Floating(FloatingKind::Float) => write!(f, "float"),
+            CType::Floating(FloatingKind::Double) => write!(f, "double"),
+            CType::Pointer(element) if element.is_pointer() => write!(f, "{}*", element),
+            CType::Pointer(element) if element.is_array() => {
+                let (element, dimensions) = split_dimensions(element);
+                write!(f, "{} (*){}", element, dimensions)
+            }
+            CType::Pointer(element) => write!(f, "{} *", element),
+            CType::Array(_, _) => {
+                let (element, dimensions) = split_dimensions(self);
+                write!(f, "{} {}", element, dimensions)
+            }
+            CType::Function(return_type, parameters) => {
+                let parameters: Vec<String> = parameters
+                    .iter()
+                    .map(|parameter| parameter.to_string())
+                    .collect();
+                write!(f, "{} ({})", return_type, parameters.join(", "))
+            }
+        }
+    }
+}
+
+// the innermost element type of an array and its dimensions as written, as
+// in `int` and `[2][3]`
+fn split_dimensions(ctype: &CType) -> (&CType, String) {
+    let mut dimensions = String::new();
+    let mut element = ctype;
+    while let CType::Array(inner, size) = element {
+        dimensions.push_str(&format!("[{}]", size));
+        element = inner;
+    }
+    (element, dimensions)
+}
+
+fn pointer_type(element: BasicTypeEnum) -> PointerType {
+    match element {
+        BasicTypeEnum::ArrayType(ty) => ty.ptr_type(AddressSpace::Generic),
+        BasicTypeEnum::FloatType(ty) => ty.ptr_type(AddressSpace::Generic),
+        BasicTypeEnum::IntType(ty) => ty.ptr_type(AddressSpace::Generic),
+        BasicTypeEnum::PointerType(ty) => ty.ptr_type(AddressSpace::Generic),
+        BasicTypeEnum::StructType(ty) => ty.ptr_type(AddressSpace::Generic),
+        BasicTypeEnum::VectorType(ty) => ty.ptr_type(AddressSpace::Generic),
+    }
+}
+
+fn array_type(element: BasicTypeEnum, size: u32) -> ArrayType {
+    match element {
+        BasicTypeEnum::ArrayType(ty) => ty.array_type(size),
+        BasicTypeEnum::FloatType(ty) => ty.array_type(size),
+        BasicTypeEnum::IntType(ty) => ty.array_type(size),
+        BasicTypeEnum::PointerType(ty) => ty.array_type(size),
+        BasicTypeEnum::StructType(ty) => ty.array_type(size),
+        BasicTypeEnum::VectorType(ty) => ty.array_type(size),
+    }
+}
@@ -1,26 +1,9 @@
 use regex::Regex;
-use std::collections::HashMap;
 
-use crate::lexer::token::{Associativity, Property, Token, Tokens};
+use crate::lexer::token::{Token, Tokens};
 
 pub mod token;
 
-pub fn get_property(op: &String) -> Property {
-    let mut map = HashMap::new();
-    map.insert("=", (2, Associativity::Right));
-    map.insert("+", (12, Associativity::Left));
-    map.insert("-", (12, Associativity::Left));
-    map.insert("*", (13, Associativity::Left));
-    map.insert("/", (13, Associativity::Left));
-    map.insert("&", (15, Associativity::Left));
-    let op: &str = &op;
-    let (precedence, associativity): (u32, Associativity) = map[op].clone();
-    Property {
-        precedence,
-        associativity,
-    }
-}
-
 pub struct Lexer {
     re: Regex,
     names: Vec<&'static str>,
@@ -30,7 +13,11 @@ impl Lexer {
     // static constructor
     pub fn new() -> Lexer {
         let token_patterns = vec![
-            ("TYPE", r"int"),
+            ("INCLUDE", r"#include\s*<[\w./]+>"),
+            (
+                "TYPE",
+                r"(int|char|short|long|signed|unsigned|float|double|_Bool|void)\b",
+            ),
             ("PARENS", r"\("),
             ("PARENE", r"\)"),
             ("BLOCKS", r"\{"),
@@ -39,9 +26,19 @@ impl Lexer {
             ("SQUAREE", r"\]"),
             ("SEMI", r";"),
             ("COMMA", r","),
+            ("COLON", r":"),
             ("RETURN", r"return"),
-            ("NUM", r"(\d+(\.\d)*)"),
-            ("OP", r"(\+|-|\*|/|=|,|&)"),
+            ("SIZEOF", r"sizeof\b"),
+            (
+                "FLOAT",
+                r"(0[xX]([0-9a-fA-F]+\.?[0-9a-fA-F]*|\.[0-9a-fA-F]+)[pP][+-]?\d+|(\d+\.\d*|\.\d+)([eE][+-]?\d+)?|\d+[eE][+-]?\d+)[fFlL]?",
+            ),
+            ("NUM", r"(\d+[uUlL]*)"),
+            ("STR", r#""(\\.|[^"\\])*""#),
+            (
+                "OP",
+                r"(\+\+|--|<<=|>>=|\+=|-=|\*=|/=|%=|&=|\|=|\^=|<<|>>|<=|>=|==|!=|&&|\|\||\+|-|\*|/|%|=|<|>|!|,|&|\||\^|~|\?)",
+            ),
             ("IDE", r"\w+"),
         ];
         let re = make_regex(&token_patterns);
@@ -55,6 +52,8 @@ impl Lexer {
     }
     fn tokenize(&self, code: String) -> Tokens {
         let mut tokens: Vec<Token> = Vec::new();
+        // identifiers defined by the included headers
+        let mut macros: Vec<(String, Token)> = Vec::new();
         for caps in self.re.captures_iter(&code) {
             let mut typ = String::from("nil");
             let val = String::from(&caps[0]);
@@ -64,6 +63,11 @@ impl Lexer {
                 }
             }
             let token = match typ.as_ref() {
+                "INCLUDE" => {
+                    let name = &val[val.find('<').expect("<") + 1..val.len() - 1];
+                    macros.append(&mut builtin_header(name));
+                    continue;
+                }
                 "TYPE" => Token::Type(val),
                 "PARENS" => Token::ParenS,
                 "PARENE" => Token::ParenE,
@@ -73,13 +77,20 @@ impl Lexer {
                 "SQUAREE" => Token::SquareE,
                 "SEMI" => Token::Semi,
                 "COMMA" => Token::Comma,
+                "COLON" => Token::Colon,
                 "RETURN" => Token::Return,
+                "SIZEOF" => Token::Sizeof,
+                "FLOAT" => Token::Float(val),
                 "NUM" => Token::Num(val),
+                "STR" => Token::Str(unescape(&val[1..val.len() - 1])),
                 "OP" => {
                     let val = val.trim_end().to_string();
-                    Token::Op(val.clone(), get_property(&val))
+                    Token::Op(val)
                 }
-                "IDE" => Token::Ide(val),
+                "IDE" => match macros.iter().find(|(name, _)| *name == val) {
+                    Some((_, token)) => token.clone(),
+                    None => Token::Ide(val),
+                },
                 _ => panic!("This is not an expected panic"),
             };
             tokens.push(token);
@@ -87,6 +98,85 @@ impl Lexer {
         Tokens { tokens }
     }
 }
+// the identifiers a built-in header defines, with the token each one stands
+// for; there is no preprocessor, so only these headers can be included
+fn builtin_header(name: &str) -> Vec<(String, Token)> {
+    let definitions = match name {
+        "stdbool.h" => vec![
+            ("bool", Token::Type("_Bool".to_string())),
+            ("true", Token::Num("1".to_string())),
+            ("false", Token::Num("0".to_string())),
+            ("__bool_true_false_are_defined", Token::Num("1".to_string())),
+        ],
+        _ => panic!("error: \'{}\' file not found", name),
+    };
+    definitions
+        .into_iter()
+        .map(|(name, token)| (name.to_string(), token))
+        .collect()
+}
+
+// the bytes of a string literal, given its text between the quotes
+fn unescape(literal: &str) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    let mut chars = literal.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            let mut buffer = [0; 4];
+            bytes.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+            continue;
+        }
+        let byte = match chars.next() {
+            Some('a') => 0x07,
+            Some('b') => 0x08,
+            Some('f') => 0x0c,
+            Some('n') => b'\n',
+            Some('r') => b'\r',
+            Some('t') => b'\t',
+            Some('v') => 0x0b,
+            Some(c @ '\\') | Some(c @ '\'') | Some(c @ '"') | Some(c @ '?') => c as u8,
+            // up to three octal digits, as in `\0` or `\177`
+            Some(c @ '0'..='7') => {
+                let mut value = c.to_digit(8).unwrap();
+                for _ in 0..2 {
+                    match chars.peek().and_then(|c| c.to_digit(8)) {
+                        Some(digit) => {
+                            value = value * 8 + digit;
+                            chars.next();
+                        }
+                        None => break,
+                    }
+                }
+                if value > 0xff {
+                    panic!("error: octal escape sequence out of range");
+                }
+                value as u8
+            }
+            // any number of hexadecimal digits, as in `\x7f`
+            Some('x') => {
+                let mut value: u32 = 0;
+                let mut digits = 0;
+                while let Some(digit) = chars.peek().and_then(|c| c.to_digit(16)) {
+                    value = value.saturating_mul(16).saturating_add(digit);
+                    digits += 1;
+                    chars.next();
+                }
+                if digits == 0 {
+                    panic!("error: \\x used with no following hex digits");
+                }
+                if value > 0xff {
+                    panic!("error: hex escape sequence out of range");
+                }
+                value as u8
+            }
+            Some(c) => panic!("error: unknown escape sequence '\\{}'", c),
+            None => panic!("error: unterminated escape sequence"),
+        };
+        bytes.push(byte);
+    }
+    bytes
+}
+
 fn make_regex(token_patterns: &Vec<(&str, &str)>) -> String {
     token_patterns
         .into_iter()
@@ -1,15 +1,3 @@
-#[derive(Debug, PartialEq, Clone)]
-pub enum Associativity {
-    Right,
-    Left,
-}
-
-#[derive(Debug, PartialEq, Clone)]
-pub struct Property {
-    pub precedence: u32,
-    pub associativity: Associativity,
-}
-
 #[derive(Debug, PartialEq, Clone)]
 pub enum Token {
     Type(String), // TODO change to Enum
@@ -21,9 +9,13 @@ pub enum Token {
     SquareE,
     Semi,
     Comma,
+    Colon,
     Return,
+    Sizeof,
     Num(String),
-    Op(String, Property),
+    Float(String),
+    Str(Vec<u8>),
+    Op(String),
     Ide(String),
 }
 
@@ -56,10 +48,10 @@ impl Tokens {
         }
         return Err("Token::Ide not found".to_string());
     }
-    pub fn consume_operator(&mut self) -> Result<(String, Property), String> {
-        if let Some(Token::Op(op, property)) = self.peek(0) {
+    pub fn consume_operator(&mut self) -> Result<String, String> {
+        if let Some(Token::Op(op)) = self.peek(0) {
             self.pop(); // consume
-            return Ok((op, property));
+            return Ok(op);
         }
         return Err("Token::Ope not found".to_string());
     }
@@ -70,6 +62,13 @@ impl Tokens {
         }
         return Err("Token::Semi not found".to_string());
     }
+    pub fn consume_colon(&mut self) -> Result<Token, String> {
+        if let Some(Token::Colon) = self.peek(0) {
+            self.pop(); // consume
+            return Ok(Token::Colon);
+        }
+        return Err("Token::Colon not found".to_string());
+    }
     pub fn consume_block_s(&mut self) -> Result<Token, String> {
         if let Some(Token::BlockS) = self.peek(0) {
             self.pop(); // consume
@@ -126,4 +125,11 @@ impl Tokens {
         }
         return Err("Token::Return not found".to_string());
     }
+    pub fn consume_sizeof(&mut self) -> Result<Token, String> {
+        if let Some(Token::Sizeof) = self.peek(0) {
+            self.pop(); // consume
+            return Ok(Token::Sizeof);
+        }
+        return Err("Token::Sizeof not found".to_string());
+    }
 }
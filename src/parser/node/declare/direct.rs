@@ -1,7 +1,9 @@
 use crate::emitter::emitter::Emitter;
-use crate::emitter::environment::{ArrayVariable, IntVariable, Value, Variable};
+use crate::emitter::environment::{Value, Variable};
+use crate::emitter::types::CType;
 use crate::lexer::token::{Token, Tokens};
-use crate::parser::node::expression::unary::UnaryNode;
+use crate::parser::node::declare::initializer::InitializerNode;
+use crate::parser::node::declare::type_name::TypeNameNode;
 use crate::parser::node::expression::ExpressionNode;
 
 #[derive(Debug, PartialEq, Clone)]
@@ -12,8 +14,8 @@ pub enum DirectDeclareNode {
 impl DirectDeclareNode {
     pub fn new(tokens: &mut Tokens) -> DirectDeclareNode {
         // expect Token::Type(_) as tokens.peek(0)
-        // expect Token::Ide(_) as tokens.peek(1)
-        match tokens.peek(2) {
+        // expect Token::Ide(_) after the type specifiers
+        match tokens.peek(TypeNameNode::count_specifiers(tokens) + 1) {
             Some(token) => match token {
                 Token::SquareS => DirectDeclareNode::Array(ArrayDeclareNode::new(tokens)),
                 _ => DirectDeclareNode::Variable(VariableDeclareNode::new(tokens)),
@@ -32,19 +34,21 @@ impl DirectDeclareNode {
 #[derive(Debug, PartialEq, Clone)]
 pub struct VariableDeclareNode {
     pub identifier: String,
+    pub ctype: CType,
     pub init_expression: Option<ExpressionNode>,
 }
 impl VariableDeclareNode {
     fn new(tokens: &mut Tokens) -> VariableDeclareNode {
-        let _variable_type = tokens.consume_type().expect("type");
+        let ctype = TypeNameNode::new_base_type(tokens);
         let identifier = tokens.expect_identifier().expect("identifier");
         match tokens.peek(1) {
             Some(token) => match token {
-                Token::Op(op, ..) => match op.as_ref() {
+                Token::Op(op) => match op.as_ref() {
                     "=" => {
-                        let init_expression = Some(ExpressionNode::new(tokens));
+                        let init_expression = Some(ExpressionNode::new_assignment(tokens));
                         VariableDeclareNode {
                             identifier,
+                            ctype,
                             init_expression,
                         }
                     }
@@ -54,6 +58,7 @@ impl VariableDeclareNode {
                     let identifier = tokens.consume_identifier().expect("identifier");
                     VariableDeclareNode {
                         identifier,
+                        ctype,
                         init_expression: None,
                     }
                 }
@@ -62,16 +67,19 @@ impl VariableDeclareNode {
         }
     }
     pub fn emit(self, emitter: &mut Emitter) -> Value {
+        if self.ctype.is_void() {
+            panic!(
+                "error: variable \'{}\' has incomplete type \'void\'",
+                self.identifier
+            );
+        }
         match self.init_expression {
             Some(expression) => {
                 let identifier = self.identifier;
                 let alloca = emitter
                     .builder
-                    .build_alloca(emitter.context.i32_type(), &identifier);
-                let variable = Variable::Int(IntVariable {
-                    name: identifier.clone(),
-                    pointer: alloca,
-                });
+                    .build_alloca(self.ctype.to_llvm_type(emitter), &identifier);
+                let variable = Variable::new(identifier.clone(), alloca, self.ctype);
                 emitter.environment.update(identifier, variable); // TODO: impl detect redefinition
                 expression.emit(emitter)
             }
@@ -79,11 +87,8 @@ impl VariableDeclareNode {
                 let identifier = self.identifier;
                 let alloca = emitter
                     .builder
-                    .build_alloca(emitter.context.i32_type(), &identifier);
-                let variable = Variable::Int(IntVariable {
-                    name: identifier.clone(),
-                    pointer: alloca,
-                });
+                    .build_alloca(self.ctype.to_llvm_type(emitter), &identifier);
+                let variable = Variable::new(identifier.clone(), alloca, self.ctype);
                 emitter.environment.update(identifier, variable); // TODO: impl detect redefinition
                 Value::Null
             }
@@ -94,53 +99,78 @@ impl VariableDeclareNode {
 #[derive(Debug, PartialEq, Clone)]
 pub struct ArrayDeclareNode {
     pub identifier: String,
-    pub init_sizes: Vec<u32>,
+    pub element_type: CType,
+    // `None` for a dimension left empty, as in `int a[]`
+    pub init_sizes: Vec<Option<ExpressionNode>>,
+    pub initializer: Option<InitializerNode>,
 }
 impl ArrayDeclareNode {
     fn new(tokens: &mut Tokens) -> ArrayDeclareNode {
-        let _variable_type = tokens.consume_type().expect("type");
+        let element_type = TypeNameNode::new_base_type(tokens);
         let identifier = tokens.consume_identifier().expect("identifier");
         let mut init_sizes = Vec::new();
         while let Some(Token::SquareS) = tokens.peek(0) {
             tokens.consume_square_s().expect("[");
-            let size_node = ExpressionNode::new(tokens);
-            let init_size = match size_node {
-                ExpressionNode::Unary(node) => match node {
-                    UnaryNode::Primary(node) => node.get_number_u64() as u32,
-                    _ => panic!(),
-                },
-                _ => panic!(),
-            };
-            init_sizes.push(init_size);
+            if let Some(Token::SquareE) = tokens.peek(0) {
+                init_sizes.push(None);
+                tokens.consume_square_e().expect("]");
+                continue;
+            }
+            // evaluated as a constant expression once the array is emitted
+            init_sizes.push(Some(ExpressionNode::new(tokens)));
             tokens.consume_square_e().expect("]");
         }
+        let initializer = match tokens.peek(0) {
+            Some(Token::Op(ref op)) if op == "=" => {
+                tokens.pop(); // consume "="
+                Some(InitializerNode::new(tokens))
+            }
+            _ => None,
+        };
         ArrayDeclareNode {
             identifier,
+            element_type,
             init_sizes,
+            initializer,
         }
     }
+    pub fn get_type(&self, emitter: &Emitter) -> CType {
+        TypeNameNode::get_array_type(
+            &self.element_type,
+            self.init_sizes.iter().map(Option::as_ref),
+            emitter,
+        )
+    }
     pub fn emit(self, emitter: &mut Emitter) -> Value {
-        let identifier = self.identifier;
-
-        let mut init_sizes = self.init_sizes;
-        // init_sizes.reverse();
-        let mut array_type = match init_sizes.pop() {
-            Some(init_size) => emitter.context.i32_type().array_type(init_size),
-            None => panic!(),
-        };
-        while let Some(init_size) = init_sizes.pop() {
-            array_type = array_type.array_type(init_size);
+        // only the outermost dimension may be left to the initializer
+        if self
+            .init_sizes
+            .iter()
+            .skip(1)
+            .any(|init_size| init_size.is_none())
+        {
+            panic!("error: array has incomplete element type");
         }
+        let ctype = match (self.init_sizes.first(), &self.initializer) {
+            (Some(None), Some(initializer)) => initializer.complete_type(&self.get_type(emitter)),
+            (Some(None), None) => panic!(
+                "error: definition of variable \'{}\' with array type needs an explicit size",
+                self.identifier
+            ),
+            _ => self.get_type(emitter),
+        };
+        let identifier = self.identifier;
+        let array_type = ctype.to_llvm_type(emitter);
 
-        let alloca = match emitter.environment.get(&identifier) {
+        let alloca = match emitter.environment.get_local(&identifier) {
             Some(_) => panic!(format!("redefinition of {}", identifier)),
             None => emitter.builder.build_alloca(array_type, &identifier),
         };
-        let variable = Variable::Array(ArrayVariable {
-            name: identifier.clone(),
-            pointer: alloca,
-        });
+        let variable = Variable::new(identifier.clone(), alloca, ctype.clone());
         emitter.environment.update(identifier, variable);
+        if let Some(initializer) = self.initializer {
+            initializer.emit(alloca, &ctype, emitter);
+        }
         Value::Null
     }
 }
@@ -0,0 +1,208 @@
+use std::iter::Peekable;
+use std::vec::IntoIter;
+
+use inkwell::values::PointerValue;
+
+use crate::emitter::emitter::Emitter;
+use crate::emitter::environment::Value;
+use crate::emitter::types::{CType, IntegerKind};
+use crate::lexer::token::{Token, Tokens};
+use crate::parser::node::expression::unary::primary::PrimaryNode;
+use crate::parser::node::expression::unary::UnaryNode;
+use crate::parser::node::expression::ExpressionNode;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum InitializerNode {
+    Expression(ExpressionNode),
+    List(Vec<InitializerNode>),
+    String(Vec<u8>),
+}
+impl InitializerNode {
+    pub fn new(tokens: &mut Tokens) -> InitializerNode {
+        let msg = "InitializerNode";
+        match tokens.peek(0) {
+            Some(Token::BlockS) => {
+                tokens.consume_block_s().expect(msg);
+                let mut initializers = Vec::new();
+                while let Some(token) = tokens.peek(0) {
+                    if token == Token::BlockE {
+                        break;
+                    }
+                    initializers.push(InitializerNode::new(tokens));
+                    // the last initializer may be followed by a ","
+                    match tokens.peek(0) {
+                        Some(Token::Comma) => {
+                            tokens.pop();
+                        }
+                        Some(Token::BlockE) => break,
+                        _ => panic!("error: expected ',' or '}' after initializer"),
+                    }
+                }
+                tokens.consume_block_e().expect(msg);
+                InitializerNode::List(initializers)
+            }
+            Some(Token::Str(string)) => {
+                tokens.pop();
+                InitializerNode::String(string)
+            }
+            _ => InitializerNode::Expression(ExpressionNode::new_assignment(tokens)),
+        }
+    }
+    // `ctype` with its outermost dimension sized to fit this initializer, as in
+    // `int a[] = {1, 2, 3}`
+    pub fn complete_type(&self, ctype: &CType) -> CType {
+        let element = ctype.get_element_type();
+        let size = match self {
+            // room for the terminating '\0'
+            InitializerNode::String(string) => string.len() as u32 + 1,
+            InitializerNode::List(initializers) => {
+                let mut initializers = initializers.clone().into_iter().peekable();
+                let mut size = 0;
+                while initializers.peek().is_some() {
+                    flatten_element(&element, &mut initializers, &mut Vec::new());
+                    size += 1;
+                }
+                size
+            }
+            InitializerNode::Expression(_) => {
+                panic!("error: array initializer must be an initializer list")
+            }
+        };
+        CType::Array(Box::new(element), size)
+    }
+    // initialize the array of type `ctype` at `pointer`, element by element
+    pub fn emit(self, pointer: PointerValue, ctype: &CType, emitter: &mut Emitter) {
+        let mut leaves = Vec::new();
+        match self {
+            InitializerNode::List(initializers) => {
+                let mut initializers = initializers.into_iter().peekable();
+                flatten(ctype, &mut initializers, &mut leaves);
+                if initializers.peek().is_some() {
+                    panic!("error: excess elements in array initializer");
+                }
+            }
+            InitializerNode::String(string) => flatten_string(ctype, string, &mut leaves),
+            InitializerNode::Expression(_) => {
+                panic!("error: array initializer must be an initializer list")
+            }
+        }
+
+        let mut dimensions = Vec::new();
+        let mut scalar_type = ctype.clone();
+        while let CType::Array(element, size) = scalar_type {
+            dimensions.push(size as u64);
+            scalar_type = *element;
+        }
+        let index_type = emitter.context.i32_type();
+        for (index, leaf) in leaves.into_iter().enumerate() {
+            // the position of the element in each dimension, outermost first
+            let mut indices = Vec::new();
+            let mut rest = index as u64;
+            for size in dimensions.iter().rev() {
+                indices.push(index_type.const_int(rest % size, false));
+                rest /= size;
+            }
+            indices.push(index_type.const_int(0, false));
+            indices.reverse();
+            let element_pointer =
+                unsafe { emitter.builder.build_gep(pointer, &indices, "initializer") };
+            // elements without an initializer are zero
+            let value = match leaf {
+                Some(expression) => {
+                    let expression_type = expression.get_type(emitter).decay();
+                    let value = expression.emit(emitter);
+                    emitter.emit_conversion(value, &expression_type, &scalar_type)
+                }
+                None => {
+                    let zero = Value::Int(index_type.const_int(0, false));
+                    emitter.emit_conversion(zero, &CType::INT, &scalar_type)
+                }
+            };
+            match value.get_basic_value() {
+                Ok(value) => emitter.builder.build_store(element_pointer, value),
+                Err(msg) => panic!("{}", msg),
+            };
+        }
+    }
+}
+
+// append one entry for each scalar inside an object of type `ctype`, taking its
+// initializers from `initializers`; the braces around a sub-array may be left
+// out, in which case it takes as many initializers as it has elements
+fn flatten(
+    ctype: &CType,
+    initializers: &mut Peekable<IntoIter<InitializerNode>>,
+    leaves: &mut Vec<Option<ExpressionNode>>,
+) {
+    let (element, size) = match ctype {
+        CType::Array(element, size) => (element, *size),
+        _ => {
+            let leaf = match initializers.next() {
+                Some(InitializerNode::Expression(expression)) => Some(expression),
+                // a scalar may be enclosed in braces of its own
+                Some(InitializerNode::List(list)) => match list.into_iter().next() {
+                    Some(InitializerNode::Expression(expression)) => Some(expression),
+                    None => None,
+                    _ => panic!("error: invalid scalar initializer"),
+                },
+                Some(InitializerNode::String(_)) => panic!("error: invalid scalar initializer"),
+                None => None,
+            };
+            leaves.push(leaf);
+            return;
+        }
+    };
+    for _ in 0..size {
+        flatten_element(element, initializers, leaves);
+    }
+}
+
+// an element of an array, which is a sub-array either with braces of its own or
+// without them
+fn flatten_element(
+    element: &CType,
+    initializers: &mut Peekable<IntoIter<InitializerNode>>,
+    leaves: &mut Vec<Option<ExpressionNode>>,
+) {
+    match initializers.peek() {
+        Some(InitializerNode::List(_)) if element.is_array() => {
+            if let Some(InitializerNode::List(list)) = initializers.next() {
+                let mut list = list.into_iter().peekable();
+                flatten(element, &mut list, leaves);
+                if list.peek().is_some() {
+                    panic!("error: excess elements in array initializer");
+                }
+            }
+        }
+        Some(InitializerNode::String(_)) if element.is_array() => {
+            if let Some(InitializerNode::String(string)) = initializers.next() {
+                flatten_string(element, string, leaves);
+            }
+        }
+        _ => flatten(element, initializers, leaves),
+    }
+}
+
+// a `char` array initialized with the characters of a string literal
+fn flatten_string(ctype: &CType, bytes: Vec<u8>, leaves: &mut Vec<Option<ExpressionNode>>) {
+    let size = match ctype {
+        CType::Array(element, size) => match **element {
+            // plain, signed and unsigned `char` alike
+            CType::Integer {
+                kind: IntegerKind::Char,
+                ..
+            } => *size as usize,
+            _ => panic!("error: array initializer must be an initializer list"),
+        },
+        _ => panic!("error: array initializer must be an initializer list"),
+    };
+    if bytes.len() > size {
+        panic!("error: initializer-string for char array is too long");
+    }
+    for index in 0..size {
+        let leaf = bytes.get(index).map(|byte| {
+            ExpressionNode::Unary(UnaryNode::Primary(PrimaryNode::Num(byte.to_string())))
+        });
+        leaves.push(leaf);
+    }
+}
@@ -1,11 +1,15 @@
 pub mod direct;
+pub mod initializer;
+pub mod parameter;
 pub mod pointer;
+pub mod type_name;
 
 use crate::emitter::emitter::Emitter;
 use crate::emitter::environment::Value;
 use crate::lexer::token::{Token, Tokens};
 use crate::parser::node::declare::direct::DirectDeclareNode;
 use crate::parser::node::declare::pointer::PointerDeclareNode;
+use crate::parser::node::declare::type_name::TypeNameNode;
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum DeclareNode {
@@ -15,10 +19,10 @@ pub enum DeclareNode {
 impl DeclareNode {
     pub fn new(tokens: &mut Tokens) -> DeclareNode {
         // expect Token::Type(_) as tokens.peek(0)
-        match tokens.peek(1) {
+        match tokens.peek(TypeNameNode::count_specifiers(tokens)) {
             Some(token) => match token {
                 Token::Ide(_identifier) => DeclareNode::Direct(DirectDeclareNode::new(tokens)),
-                Token::Op(op, _) => match op.as_ref() {
+                Token::Op(op) => match op.as_ref() {
                     "*" => DeclareNode::Pointer(PointerDeclareNode::new(tokens)),
                     _ => panic!(),
                 },
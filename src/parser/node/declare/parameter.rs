@@ -0,0 +1,57 @@
+use crate::emitter::emitter::Emitter;
+use crate::emitter::types::CType;
+use crate::lexer::token::{Token, Tokens};
+use crate::parser::node::declare::type_name::TypeNameNode;
+use crate::parser::node::expression::ExpressionNode;
+
+// a function parameter, whose name may be left out where the function is
+// only declared, as in `int f(char *, int n);`
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParameterNode {
+    pub identifier: Option<String>,
+    pub ctype: CType,
+    pub dimensions: Vec<Option<ExpressionNode>>,
+}
+impl ParameterNode {
+    pub fn new(tokens: &mut Tokens) -> ParameterNode {
+        let msg = "ParameterNode";
+        let ctype = TypeNameNode::new_base_type(tokens);
+        let ctype = TypeNameNode::new_pointer_type(tokens, ctype);
+        let identifier = match tokens.peek(0) {
+            Some(Token::Ide(_)) => Some(tokens.consume_identifier().expect(msg)),
+            _ => None,
+        };
+        let mut dimensions = Vec::new();
+        while let Some(Token::SquareS) = tokens.peek(0) {
+            tokens.consume_square_s().expect(msg);
+            let dimension = match tokens.peek(0) {
+                Some(Token::SquareE) => None,
+                _ => Some(ExpressionNode::new(tokens)),
+            };
+            dimensions.push(dimension);
+            tokens.consume_square_e().expect(msg);
+        }
+        ParameterNode {
+            identifier,
+            ctype,
+            dimensions,
+        }
+    }
+    // a parameter declared as an array is a pointer to its element type, so
+    // only the first dimension may be left empty
+    pub fn get_type(&self, emitter: &Emitter) -> CType {
+        if self.dimensions.is_empty() {
+            return self.ctype.clone();
+        }
+        let inner_dimensions = &self.dimensions[1..];
+        if inner_dimensions.iter().any(Option::is_none) {
+            panic!("error: array has incomplete element type");
+        }
+        let element_type = TypeNameNode::get_array_type(
+            &self.ctype,
+            inner_dimensions.iter().map(Option::as_ref),
+            emitter,
+        );
+        CType::Pointer(Box::new(element_type))
+    }
+}
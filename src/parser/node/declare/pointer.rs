@@ -1,35 +1,49 @@
 use crate::emitter::emitter::Emitter;
 use crate::emitter::environment::Value;
-use crate::emitter::environment::{IntVariable, Variable};
+use crate::emitter::environment::{PointerVariable, Variable};
+use crate::emitter::types::CType;
 use crate::lexer::token::{Token, Tokens};
+use crate::parser::node::declare::type_name::TypeNameNode;
+use crate::parser::node::expression::ExpressionNode;
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct PointerDeclareNode {
     pub identifier: String,
+    pub ctype: CType,
+    pub init_expression: Option<ExpressionNode>,
 }
 impl PointerDeclareNode {
     pub fn new(tokens: &mut Tokens) -> PointerDeclareNode {
-        let _variable_type = tokens.consume_type().expect("type");
-        tokens.pop(); // consume "*"
-        let identifier = match tokens.pop() {
-            Some(token) => match token {
-                Token::Ide(identifier) => identifier,
-                _ => panic!(),
-            },
-            None => panic!(),
+        // `int`, followed by one "*" for each level of indirection
+        let ctype = TypeNameNode::new(tokens).ctype;
+        let identifier = tokens.expect_identifier().expect("identifier");
+        let init_expression = match tokens.peek(1) {
+            Some(Token::Op(ref op)) if op == "=" => Some(ExpressionNode::new_assignment(tokens)),
+            _ => {
+                tokens.consume_identifier().expect("identifier");
+                None
+            }
         };
-        PointerDeclareNode { identifier }
+        PointerDeclareNode {
+            identifier,
+            ctype,
+            init_expression,
+        }
     }
     pub fn emit(self, emitter: &mut Emitter) -> Value {
         let identifier = self.identifier;
         let alloca = emitter
             .builder
-            .build_alloca(emitter.context.i32_type(), &identifier);
-        let variable = Variable::Int(IntVariable {
+            .build_alloca(self.ctype.to_llvm_type(emitter), &identifier);
+        let variable = Variable::Pointer(PointerVariable {
             name: identifier.clone(),
             pointer: alloca,
+            ctype: self.ctype,
         });
         emitter.environment.update(identifier, variable); // TODO: impl detect redefinition
-        Value::Null
+        match self.init_expression {
+            Some(expression) => expression.emit(emitter),
+            None => Value::Null,
+        }
     }
 }
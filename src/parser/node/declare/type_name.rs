@@ -0,0 +1,124 @@
+use crate::emitter::emitter::Emitter;
+use crate::emitter::types::{CType, IntegerKind};
+use crate::lexer::token::{Token, Tokens};
+use crate::parser::node::expression::ExpressionNode;
+
+// a type without a declared identifier, as in `sizeof(int *)`
+#[derive(Debug, PartialEq, Clone)]
+pub struct TypeNameNode {
+    // the type without its array dimensions
+    pub ctype: CType,
+    pub dimensions: Vec<ExpressionNode>,
+}
+impl TypeNameNode {
+    // whether a type name starting with `token` follows, which tells a cast or
+    // `sizeof(type)` apart from a parenthesised expression
+    pub fn is_type_name(token: &Option<Token>) -> bool {
+        matches!(token, Some(Token::Type(_)))
+    }
+    // the number of type specifier tokens at the front of `tokens`, as in
+    // `unsigned long x`
+    pub fn count_specifiers(tokens: &Tokens) -> usize {
+        let mut count = 0;
+        while let Some(Token::Type(_)) = tokens.peek(count) {
+            count += 1;
+        }
+        count
+    }
+    // the type named by a list of type specifiers, as in `int x` or
+    // `unsigned long long *p`, which may come in any order
+    pub fn new_base_type(tokens: &mut Tokens) -> CType {
+        let mut specifiers = Vec::new();
+        while let Some(Token::Type(_)) = tokens.peek(0) {
+            specifiers.push(tokens.consume_type().expect("type"));
+        }
+        let count = |name: &str| specifiers.iter().filter(|s| *s == name).count();
+        let signedness = count("signed") + count("unsigned");
+        let signed = count("unsigned") == 0;
+        let kind = match (
+            count("void"),
+            count("char"),
+            count("short"),
+            count("long"),
+            count("int"),
+            count("float"),
+            count("double"),
+            count("_Bool"),
+        ) {
+            (1, 0, 0, 0, 0, 0, 0, 0) if signedness == 0 => return CType::Void,
+            (0, 0, 0, 0, 0, 1, 0, 0) if signedness == 0 => return CType::FLOAT,
+            // `long double` is treated as `double`
+            (0, 0, 0, 0..=1, 0, 0, 1, 0) if signedness == 0 => return CType::DOUBLE,
+            (0, 0, 0, 0, 0, 0, 0, 1) if signedness == 0 => return CType::BOOL,
+            (0, 1, 0, 0, 0, 0, 0, 0) => IntegerKind::Char,
+            (0, 0, 1, 0, 0..=1, 0, 0, 0) => IntegerKind::Short,
+            (0, 0, 0, 0, 0..=1, 0, 0, 0) => IntegerKind::Int,
+            (0, 0, 0, 1, 0..=1, 0, 0, 0) => IntegerKind::Long,
+            (0, 0, 0, 2, 0..=1, 0, 0, 0) => IntegerKind::LongLong,
+            _ => panic!(
+                "error: invalid combination of type specifiers \'{}\'",
+                specifiers.join(" ")
+            ),
+        };
+        if signedness > 1 {
+            panic!(
+                "error: invalid combination of type specifiers \'{}\'",
+                specifiers.join(" ")
+            );
+        }
+        CType::Integer { kind, signed }
+    }
+    pub fn new(tokens: &mut Tokens) -> TypeNameNode {
+        let msg = "TypeNameNode";
+        let ctype = TypeNameNode::new_base_type(tokens);
+        let ctype = TypeNameNode::new_pointer_type(tokens, ctype);
+        let mut dimensions = Vec::new();
+        while let Some(Token::SquareS) = tokens.peek(0) {
+            tokens.consume_square_s().expect(msg);
+            dimensions.push(ExpressionNode::new(tokens));
+            tokens.consume_square_e().expect(msg);
+        }
+        TypeNameNode { ctype, dimensions }
+    }
+    // `ctype` followed by one "*" for each level of indirection
+    pub fn new_pointer_type(tokens: &mut Tokens, ctype: CType) -> CType {
+        let mut ctype = ctype;
+        while let Some(Token::Op(op)) = tokens.peek(0) {
+            if op != "*" {
+                break;
+            }
+            tokens.pop(); // consume "*"
+            ctype = CType::Pointer(Box::new(ctype));
+        }
+        ctype
+    }
+    pub fn get_type(&self, emitter: &Emitter) -> CType {
+        TypeNameNode::get_array_type(&self.ctype, self.dimensions.iter().map(Some), emitter)
+    }
+    // `ctype` as the element of an array with `dimensions`, the last of which
+    // is the innermost one; an empty dimension is given size 0
+    pub fn get_array_type<'a>(
+        ctype: &CType,
+        dimensions: impl DoubleEndedIterator<Item = Option<&'a ExpressionNode>>,
+        emitter: &Emitter,
+    ) -> CType {
+        dimensions.rev().fold(ctype.clone(), |ctype, dimension| {
+            let size = match dimension {
+                Some(dimension) => TypeNameNode::get_array_size(dimension, emitter),
+                None => 0,
+            };
+            CType::Array(Box::new(ctype), size)
+        })
+    }
+    // an array dimension has to be a positive integer constant expression
+    pub fn get_array_size(dimension: &ExpressionNode, emitter: &Emitter) -> u32 {
+        if !dimension.get_type(emitter).is_integer() {
+            panic!("error: size of array has non-integer type");
+        }
+        match dimension.evaluate_constant(emitter) {
+            Ok(size) if size > 0 => size as u32,
+            Ok(_) => panic!("error: array size must be greater than zero"),
+            Err(msg) => panic!("{}", msg),
+        }
+    }
+}
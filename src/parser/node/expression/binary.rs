@@ -1,9 +1,16 @@
+use inkwell::values::{FloatValue, IntValue};
+use inkwell::{FloatPredicate, IntPredicate};
+
 use crate::emitter::emitter::Emitter;
-use crate::emitter::environment::{Value, Variable};
-use crate::lexer::token::{Associativity, Token, Tokens};
-use crate::parser::node::expression::unary::suffix::SuffixNode;
+use crate::emitter::environment::Value;
+use crate::emitter::types::CType;
+use crate::lexer::token::{Token, Tokens};
+use crate::parser::node::expression::conditional::ConditionalNode;
+use crate::parser::node::expression::precedence::{
+    infix_binding_power, min_binding_power, ASSIGNMENT,
+};
 use crate::parser::node::expression::unary::UnaryNode;
-use crate::parser::node::expression::ExpressionNode;
+use crate::parser::node::expression::{ExpressionNode, NOT_CONSTANT};
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct BinaryNode {
@@ -13,117 +20,447 @@ pub struct BinaryNode {
 }
 impl BinaryNode {
     pub fn new(tokens: &mut Tokens) -> ExpressionNode {
-        let lhs = ExpressionNode::Unary(UnaryNode::new(tokens));
-        BinaryNode::binary_expression(lhs, tokens, 0)
+        BinaryNode::binary_expression(tokens, 0)
+    }
+    // an expression that stops at a top-level `,`, as in function arguments
+    pub fn new_assignment(tokens: &mut Tokens) -> ExpressionNode {
+        BinaryNode::binary_expression(tokens, min_binding_power(ASSIGNMENT))
     }
-    fn binary_expression(
-        mut lhs: ExpressionNode,
-        tokens: &mut Tokens,
-        min_precedence: u32,
-    ) -> ExpressionNode {
+    // Pratt parser: keep folding infix operators into `lhs` while they bind at
+    // least as tightly as `min_binding_power`
+    fn binary_expression(tokens: &mut Tokens, min_binding_power: u32) -> ExpressionNode {
+        let mut lhs = ExpressionNode::Unary(UnaryNode::new(tokens));
         while let Some(token) = tokens.peek(0) {
-            match token {
-                Token::Op(_op, property) => {
-                    let (root_precedence, root_associativity) =
-                        (property.clone().precedence, property.clone().associativity);
-                    if root_precedence < min_precedence {
-                        break;
-                    }
-                    let (op, property) = tokens
-                        .consume_operator()
-                        .expect("BinaryNode, binary_expression");
-                    let op = Token::Op(op, property);
-                    let mut rhs = ExpressionNode::new(tokens);
-                    while let Some(Token::Op(_, property2)) = tokens.peek(0) {
-                        let (precedence, _associativity) =
-                            (property2.precedence, property2.associativity);
-                        match root_associativity {
-                            Associativity::Right => {
-                                if root_precedence > precedence {
-                                    break;
-                                }
-                            }
-                            Associativity::Left => {
-                                if root_precedence >= precedence {
-                                    break;
-                                }
-                            }
-                        }
-                        rhs = BinaryNode::binary_expression(rhs, tokens, precedence)
-                    }
-                    lhs = ExpressionNode::Binary(BinaryNode {
-                        op,
-                        lhs: Box::new(lhs),
-                        rhs: Box::new(rhs),
-                    });
-                }
-                _ => break,
+            let (left_binding_power, right_binding_power) = match infix_binding_power(&token) {
+                Some(binding_power) => binding_power,
+                None => break,
+            };
+            if left_binding_power < min_binding_power {
+                break;
             }
+            let op = tokens.pop().expect("BinaryNode, binary_expression");
+            if op == Token::Op("?".to_string()) {
+                // the operand between `?` and `:` is parsed as if parenthesised
+                let then_expression = BinaryNode::binary_expression(tokens, 0);
+                tokens.consume_colon().expect("BinaryNode, conditional");
+                let else_expression = BinaryNode::binary_expression(tokens, right_binding_power);
+                lhs = ExpressionNode::Conditional(ConditionalNode {
+                    condition: Box::new(lhs),
+                    then_expression: Box::new(then_expression),
+                    else_expression: Box::new(else_expression),
+                });
+                continue;
+            }
+            let rhs = BinaryNode::binary_expression(tokens, right_binding_power);
+            lhs = ExpressionNode::Binary(BinaryNode {
+                op,
+                lhs: Box::new(lhs),
+                rhs: Box::new(rhs),
+            });
         }
         lhs
     }
+    pub fn get_type(&self, emitter: &Emitter) -> CType {
+        match &self.op {
+            Token::Op(op) => match op.as_ref() {
+                "=" | "+=" | "-=" | "*=" | "/=" | "%=" | "&=" | "|=" | "^=" | "<<=" | ">>=" => {
+                    self.lhs.get_type(emitter)
+                }
+                "&&" | "||" => CType::INT,
+                _ => BinaryNode::result_type(
+                    op,
+                    &self.lhs.get_type(emitter).decay(),
+                    &self.rhs.get_type(emitter).decay(),
+                ),
+            },
+            Token::Comma => self.rhs.get_type(emitter).decay(),
+            _ => panic!(),
+        }
+    }
+    // the type of the object an assignment stores to
+    fn assignment_type(lhs: &ExpressionNode, emitter: &Emitter) -> CType {
+        match lhs.get_type(emitter) {
+            CType::Array(_, _) => panic!("error: array type is not assignable"),
+            ctype => ctype,
+        }
+    }
+    fn result_type(op: &str, lhs_type: &CType, rhs_type: &CType) -> CType {
+        match op {
+            // comparisons yield an `int` that is 0 or 1
+            "==" | "!=" | "<" | "<=" | ">" | ">=" => CType::INT,
+            "-" if lhs_type.is_pointer() && rhs_type.is_pointer() => CType::PTRDIFF_T,
+            "+" | "-" if lhs_type.is_pointer() => lhs_type.clone(),
+            "+" if rhs_type.is_pointer() => rhs_type.clone(),
+            _ if lhs_type.is_pointer() || rhs_type.is_pointer() => {
+                panic!("error: invalid operands to binary expression")
+            }
+            _ => BinaryNode::operation_type(op, lhs_type, rhs_type),
+        }
+    }
+    // the type both integer operands of `op` are converted to before it is
+    // applied
+    fn operation_type(op: &str, lhs_type: &CType, rhs_type: &CType) -> CType {
+        match op {
+            // the result of a shift has the type of its left operand
+            "<<" | ">>" => lhs_type.promote(),
+            _ => lhs_type.usual_arithmetic_conversion(rhs_type),
+        }
+    }
+    pub fn evaluate_constant(&self, emitter: &Emitter) -> Result<i64, String> {
+        let op = match &self.op {
+            Token::Op(op) => op.as_str(),
+            // the comma operator is not allowed in a constant expression
+            _ => return Err(NOT_CONSTANT.to_string()),
+        };
+        match op {
+            "=" | "+=" | "-=" | "*=" | "/=" | "%=" | "&=" | "|=" | "^=" | "<<=" | ">>=" => {
+                Err(NOT_CONSTANT.to_string())
+            }
+            "&&" => {
+                if self.lhs.evaluate_constant(emitter)? == 0 {
+                    return Ok(0);
+                }
+                Ok((self.rhs.evaluate_constant(emitter)? != 0) as i64)
+            }
+            "||" => {
+                if self.lhs.evaluate_constant(emitter)? != 0 {
+                    return Ok(1);
+                }
+                Ok((self.rhs.evaluate_constant(emitter)? != 0) as i64)
+            }
+            _ => {
+                let lhs_type = self.lhs.get_type(emitter).decay();
+                let rhs_type = self.rhs.get_type(emitter).decay();
+                if !lhs_type.is_integer() || !rhs_type.is_integer() {
+                    return Err(NOT_CONSTANT.to_string());
+                }
+                let ctype = BinaryNode::operation_type(op, &lhs_type, &rhs_type);
+                let lhs = ctype.convert_constant(self.lhs.evaluate_constant(emitter)?, emitter);
+                let rhs = match op {
+                    // the shift count keeps its own type
+                    "<<" | ">>" => self.rhs.evaluate_constant(emitter)?,
+                    _ => ctype.convert_constant(self.rhs.evaluate_constant(emitter)?, emitter),
+                };
+                let value = BinaryNode::evaluate_operation(op, lhs, rhs, ctype.is_signed())?;
+                Ok(self.get_type(emitter).convert_constant(value, emitter))
+            }
+        }
+    }
+    // the constant counterpart of `emit_operation`; unsigned operands are
+    // compared and divided as `u64`
+    fn evaluate_operation(op: &str, lhs: i64, rhs: i64, signed: bool) -> Result<i64, String> {
+        let value = match op {
+            "+" => lhs.wrapping_add(rhs),
+            "-" => lhs.wrapping_sub(rhs),
+            "*" => lhs.wrapping_mul(rhs),
+            "/" | "%" if rhs == 0 => {
+                return Err("error: division by zero in a constant expression".to_string())
+            }
+            "/" if signed => lhs.wrapping_div(rhs),
+            "/" => ((lhs as u64) / (rhs as u64)) as i64,
+            "%" if signed => lhs.wrapping_rem(rhs),
+            "%" => ((lhs as u64) % (rhs as u64)) as i64,
+            "&" => lhs & rhs,
+            "|" => lhs | rhs,
+            "^" => lhs ^ rhs,
+            "<<" => lhs.wrapping_shl(rhs as u32),
+            ">>" if signed => lhs.wrapping_shr(rhs as u32),
+            ">>" => (lhs as u64).wrapping_shr(rhs as u32) as i64,
+            "==" => (lhs == rhs) as i64,
+            "!=" => (lhs != rhs) as i64,
+            "<" if signed => (lhs < rhs) as i64,
+            "<=" if signed => (lhs <= rhs) as i64,
+            ">" if signed => (lhs > rhs) as i64,
+            ">=" if signed => (lhs >= rhs) as i64,
+            "<" => ((lhs as u64) < (rhs as u64)) as i64,
+            "<=" => ((lhs as u64) <= (rhs as u64)) as i64,
+            ">" => ((lhs as u64) > (rhs as u64)) as i64,
+            ">=" => ((lhs as u64) >= (rhs as u64)) as i64,
+            _ => return Err(NOT_CONSTANT.to_string()),
+        };
+        Ok(value)
+    }
     pub fn emit(self, emitter: &mut Emitter) -> Value {
         // define main function
         let ret = match self.op {
-            Token::Op(op, _) => match op.as_ref() {
+            Token::Op(op) => match op.as_ref() {
                 "=" => {
+                    let ctype = BinaryNode::assignment_type(&self.lhs, emitter);
                     // lhs
-                    let alloca = match *self.lhs {
-                        ExpressionNode::Unary(node) => match node {
-                            UnaryNode::Primary(node) => {
-                                let identifier = node.get_identifier();
-                                match emitter.environment.get(&identifier) {
-                                    Some(variable) => match variable {
-                                        Variable::Int(int_variable) => int_variable.pointer,
-                                        Variable::Array(array_variable) => array_variable.pointer,
-                                        Variable::Null => panic!(),
-                                    },
-                                    None => panic!(),
-                                }
-                            }
-                            UnaryNode::Suffix(node) => match node {
-                                SuffixNode::Array(node) => node.array_element.emit_pointer(emitter),
-                                SuffixNode::FunctionCall(_node) => {
-                                    panic!("need to impl!!!!!!!!!!!!")
-                                }
-                            },
-                            _ => panic!(),
-                        },
-                        _ => panic!(),
+                    let alloca = self.lhs.emit_address(emitter);
+                    // rhs, converted to the type of the lhs
+                    let rhs_type = self.rhs.get_type(emitter).decay();
+                    let val = self.rhs.emit(emitter);
+                    let val = emitter.emit_conversion(val, &rhs_type, &ctype);
+                    match val.clone().get_basic_value() {
+                        Ok(value) => emitter.builder.build_store(alloca, value),
+                        Err(msg) => panic!("{}", msg),
                     };
-                    // rhs
-                    let val = match self.rhs.emit(emitter).get_int() {
-                        Ok(value) => value,
-                        Err(msg) => panic!(msg),
-                    };
-                    emitter.builder.build_store(alloca, val);
-                    Value::Null
+                    // an assignment evaluates to the value stored in its left operand
+                    val
                 }
-                _ => {
-                    let const_lhs = self.lhs.emit(emitter);
-                    let const_lhs = match const_lhs.get_int() {
-                        Ok(value) => value,
-                        Err(msg) => panic!(msg),
+                "+=" | "-=" | "*=" | "/=" | "%=" | "&=" | "|=" | "^=" | "<<=" | ">>=" => {
+                    let op = &op[..op.len() - 1];
+                    let lhs_type = BinaryNode::assignment_type(&self.lhs, emitter);
+                    let rhs_type = self.rhs.get_type(emitter).decay();
+                    let ctype = BinaryNode::result_type(op, &lhs_type, &rhs_type);
+                    // the address is evaluated only once
+                    let alloca = self.lhs.emit_address(emitter);
+                    let rhs = self.rhs.emit(emitter);
+                    let lhs =
+                        Value::from_basic_value(emitter.builder.build_load(alloca, "compound"));
+                    let val =
+                        BinaryNode::emit_arithmetic(op, lhs, &lhs_type, rhs, &rhs_type, emitter);
+                    let val = emitter.emit_conversion(val, &ctype, &lhs_type);
+                    match val.clone().get_basic_value() {
+                        Ok(value) => emitter.builder.build_store(alloca, value),
+                        Err(msg) => panic!("{}", msg),
                     };
-                    let const_rhs = self.rhs.emit(emitter);
-                    let const_rhs = match const_rhs.get_int() {
-                        Ok(value) => value,
-                        Err(msg) => panic!(msg),
-                    };
-                    let ret_int_val = match op.as_ref() {
-                        "+" => emitter.builder.build_int_add(const_lhs, const_rhs, "main"),
-                        "-" => emitter.builder.build_int_sub(const_lhs, const_rhs, "main"),
-                        "*" => emitter.builder.build_int_mul(const_lhs, const_rhs, "main"),
-                        "/" => emitter
-                            .builder
-                            .build_int_unsigned_div(const_lhs, const_rhs, "main"),
-                        _ => panic!("Operator not implemented."),
+                    val
+                }
+                "&&" | "||" => {
+                    // the right operand is only evaluated when the left one does
+                    // not already decide the result
+                    let lhs = self.lhs.emit(emitter);
+                    let lhs = emitter.emit_condition(lhs);
+                    let function = emitter.get_function();
+                    let lhs_block = emitter.builder.get_insert_block().expect("block");
+                    let rhs_block = emitter.context.append_basic_block(&function, "rhs");
+                    let end_block = emitter.context.append_basic_block(&function, "logical");
+                    let short_circuit = match op.as_ref() {
+                        "&&" => {
+                            emitter
+                                .builder
+                                .build_conditional_branch(lhs, &rhs_block, &end_block);
+                            emitter.context.bool_type().const_int(0, false)
+                        }
+                        _ => {
+                            emitter
+                                .builder
+                                .build_conditional_branch(lhs, &end_block, &rhs_block);
+                            emitter.context.bool_type().const_int(1, false)
+                        }
                     };
-                    Value::Int(ret_int_val)
+                    emitter.builder.position_at_end(&rhs_block);
+                    let rhs = self.rhs.emit(emitter);
+                    let rhs = emitter.emit_condition(rhs);
+                    let rhs_block = emitter.builder.get_insert_block().expect("block");
+                    emitter.builder.build_unconditional_branch(&end_block);
+                    emitter.builder.position_at_end(&end_block);
+                    let phi = emitter
+                        .builder
+                        .build_phi(emitter.context.bool_type(), "logical");
+                    phi.add_incoming(&[(&short_circuit, &lhs_block), (&rhs, &rhs_block)]);
+                    Value::Int(emitter.builder.build_int_z_extend(
+                        phi.as_basic_value().into_int_value(),
+                        CType::INT.to_llvm_type(emitter).into_int_type(),
+                        "logical",
+                    ))
+                }
+                _ => {
+                    let lhs_type = self.lhs.get_type(emitter).decay();
+                    let rhs_type = self.rhs.get_type(emitter).decay();
+                    let lhs = self.lhs.emit(emitter);
+                    let rhs = self.rhs.emit(emitter);
+                    BinaryNode::emit_arithmetic(&op, lhs, &lhs_type, rhs, &rhs_type, emitter)
                 }
             },
+            // comma operator: evaluate the left operand for its side effects only
+            Token::Comma => {
+                self.lhs.emit(emitter);
+                self.rhs.emit(emitter)
+            }
             _ => panic!(),
         };
         ret
     }
+    // apply a non-assigning operator to operands that are already evaluated
+    fn emit_arithmetic(
+        op: &str,
+        lhs: Value,
+        lhs_type: &CType,
+        rhs: Value,
+        rhs_type: &CType,
+        emitter: &mut Emitter,
+    ) -> Value {
+        if lhs_type.is_pointer() || rhs_type.is_pointer() {
+            return BinaryNode::emit_pointer_operation(op, lhs, lhs_type, rhs, rhs_type, emitter);
+        }
+        let ctype = BinaryNode::operation_type(op, lhs_type, rhs_type);
+        if ctype.is_floating() {
+            let lhs = match emitter.emit_conversion(lhs, lhs_type, &ctype).get_float() {
+                Ok(value) => value,
+                Err(msg) => panic!("{}", msg),
+            };
+            let rhs = match emitter.emit_conversion(rhs, rhs_type, &ctype).get_float() {
+                Ok(value) => value,
+                Err(msg) => panic!("{}", msg),
+            };
+            return BinaryNode::emit_float_operation(op, lhs, rhs, emitter);
+        }
+        let lhs = match emitter.emit_conversion(lhs, lhs_type, &ctype).get_int() {
+            Ok(value) => value,
+            Err(msg) => panic!("{}", msg),
+        };
+        let rhs = match emitter.emit_conversion(rhs, rhs_type, &ctype).get_int() {
+            Ok(value) => value,
+            Err(msg) => panic!("{}", msg),
+        };
+        Value::Int(BinaryNode::emit_operation(
+            op,
+            lhs,
+            rhs,
+            ctype.is_signed(),
+            emitter,
+        ))
+    }
+    fn emit_pointer_operation(
+        op: &str,
+        lhs: Value,
+        lhs_type: &CType,
+        rhs: Value,
+        rhs_type: &CType,
+        emitter: &mut Emitter,
+    ) -> Value {
+        match op {
+            "==" | "!=" | "<" | "<=" | ">" | ">=" => {
+                // pointers compare as unsigned addresses
+                let lhs = emitter.emit_conversion(lhs, lhs_type, &CType::SIZE_T);
+                let rhs = emitter.emit_conversion(rhs, rhs_type, &CType::SIZE_T);
+                match (lhs, rhs) {
+                    (Value::Int(lhs), Value::Int(rhs)) => {
+                        Value::Int(BinaryNode::emit_operation(op, lhs, rhs, false, emitter))
+                    }
+                    _ => panic!("error: invalid operands to binary expression"),
+                }
+            }
+            // the offset is scaled by the size of the pointed-to type
+            "+" | "-" => match (lhs, rhs) {
+                // only `+` may have the integer on the left
+                (Value::Int(offset), Value::Pointer(pointer)) if op == "+" => {
+                    let offset = emitter.emit_index(Value::Int(offset), lhs_type);
+                    Value::Pointer(unsafe {
+                        emitter.builder.build_gep(pointer, &[offset], "pointer")
+                    })
+                }
+                (Value::Pointer(pointer), Value::Int(offset)) => {
+                    let offset = emitter.emit_index(Value::Int(offset), rhs_type);
+                    let offset = match op {
+                        "-" => emitter.builder.build_int_neg(offset, "offset"),
+                        _ => offset,
+                    };
+                    Value::Pointer(unsafe {
+                        emitter.builder.build_gep(pointer, &[offset], "pointer")
+                    })
+                }
+                // the difference of two pointers counts elements, not bytes
+                (Value::Pointer(lhs), Value::Pointer(rhs)) if op == "-" => {
+                    let ptrdiff_type = CType::PTRDIFF_T.to_llvm_type(emitter).into_int_type();
+                    let lhs = emitter.builder.build_ptr_to_int(lhs, ptrdiff_type, "lhs");
+                    let rhs = emitter.builder.build_ptr_to_int(rhs, ptrdiff_type, "rhs");
+                    let difference = emitter.builder.build_int_sub(lhs, rhs, "difference");
+                    let size = lhs_type.get_element_type().size_of(emitter);
+                    let size = ptrdiff_type.const_int(size, false);
+                    Value::Int(
+                        emitter
+                            .builder
+                            .build_int_signed_div(difference, size, "difference"),
+                    )
+                }
+                _ => panic!("error: invalid operands to binary expression"),
+            },
+            _ => panic!("error: invalid operands to binary expression"),
+        }
+    }
+    fn emit_float_operation(
+        op: &str,
+        lhs: FloatValue,
+        rhs: FloatValue,
+        emitter: &mut Emitter,
+    ) -> Value {
+        let predicate = match op {
+            "+" => return Value::Float(emitter.builder.build_float_add(lhs, rhs, "main")),
+            "-" => return Value::Float(emitter.builder.build_float_sub(lhs, rhs, "main")),
+            "*" => return Value::Float(emitter.builder.build_float_mul(lhs, rhs, "main")),
+            "/" => return Value::Float(emitter.builder.build_float_div(lhs, rhs, "main")),
+            // every comparison with a NaN is false, except `!=`
+            "==" => FloatPredicate::OEQ,
+            "!=" => FloatPredicate::UNE,
+            "<" => FloatPredicate::OLT,
+            "<=" => FloatPredicate::OLE,
+            ">" => FloatPredicate::OGT,
+            ">=" => FloatPredicate::OGE,
+            _ => panic!("error: invalid operands to binary expression"),
+        };
+        let comparison = emitter
+            .builder
+            .build_float_compare(predicate, lhs, rhs, "main");
+        let int_type = CType::INT.to_llvm_type(emitter).into_int_type();
+        Value::Int(
+            emitter
+                .builder
+                .build_int_z_extend(comparison, int_type, "main"),
+        )
+    }
+    fn emit_operation(
+        op: &str,
+        const_lhs: IntValue,
+        const_rhs: IntValue,
+        signed: bool,
+        emitter: &mut Emitter,
+    ) -> IntValue {
+        match op {
+            "+" => emitter.builder.build_int_add(const_lhs, const_rhs, "main"),
+            "-" => emitter.builder.build_int_sub(const_lhs, const_rhs, "main"),
+            "*" => emitter.builder.build_int_mul(const_lhs, const_rhs, "main"),
+            // signed `/` truncates toward zero and signed `%` keeps the sign of
+            // the dividend
+            "/" if signed => emitter
+                .builder
+                .build_int_signed_div(const_lhs, const_rhs, "main"),
+            "/" => emitter
+                .builder
+                .build_int_unsigned_div(const_lhs, const_rhs, "main"),
+            "%" if signed => emitter
+                .builder
+                .build_int_signed_rem(const_lhs, const_rhs, "main"),
+            "%" => emitter
+                .builder
+                .build_int_unsigned_rem(const_lhs, const_rhs, "main"),
+            "&" => emitter.builder.build_and(const_lhs, const_rhs, "main"),
+            "|" => emitter.builder.build_or(const_lhs, const_rhs, "main"),
+            "^" => emitter.builder.build_xor(const_lhs, const_rhs, "main"),
+            "<<" => emitter
+                .builder
+                .build_left_shift(const_lhs, const_rhs, "main"),
+            // `>>` is an arithmetic shift on signed values and a logical one on
+            // unsigned values
+            ">>" => emitter
+                .builder
+                .build_right_shift(const_lhs, const_rhs, signed, "main"),
+            "==" | "!=" | "<" | "<=" | ">" | ">=" => {
+                let predicate = match (op, signed) {
+                    ("==", _) => IntPredicate::EQ,
+                    ("!=", _) => IntPredicate::NE,
+                    ("<", true) => IntPredicate::SLT,
+                    ("<=", true) => IntPredicate::SLE,
+                    (">", true) => IntPredicate::SGT,
+                    (">=", true) => IntPredicate::SGE,
+                    ("<", false) => IntPredicate::ULT,
+                    ("<=", false) => IntPredicate::ULE,
+                    (">", false) => IntPredicate::UGT,
+                    _ => IntPredicate::UGE,
+                };
+                let comparison = emitter
+                    .builder
+                    .build_int_compare(predicate, const_lhs, const_rhs, "main");
+                // comparisons yield an `int` that is 0 or 1
+                let int_type = CType::INT.to_llvm_type(emitter).into_int_type();
+                emitter
+                    .builder
+                    .build_int_z_extend(comparison, int_type, "main")
+            }
+            _ => panic!("Operator not implemented."),
+        }
+    }
 }
@@ -0,0 +1,86 @@
+use inkwell::values::BasicValueEnum;
+
+use crate::emitter::emitter::Emitter;
+use crate::emitter::environment::Value;
+use crate::emitter::types::CType;
+use crate::parser::node::expression::{ExpressionNode, NOT_CONSTANT};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ConditionalNode {
+    pub condition: Box<ExpressionNode>,
+    pub then_expression: Box<ExpressionNode>,
+    pub else_expression: Box<ExpressionNode>,
+}
+impl ConditionalNode {
+    pub fn get_type(&self, emitter: &Emitter) -> CType {
+        let then_type = self.then_expression.get_type(emitter).decay();
+        let else_type = self.else_expression.get_type(emitter).decay();
+        match (then_type.is_arithmetic(), else_type.is_arithmetic()) {
+            (true, true) => then_type.usual_arithmetic_conversion(&else_type),
+            // a pointer paired with a null pointer constant
+            (true, false) => else_type,
+            _ => then_type,
+        }
+    }
+    pub fn evaluate_constant(&self, emitter: &Emitter) -> Result<i64, String> {
+        let ctype = self.get_type(emitter);
+        if !ctype.is_integer() {
+            return Err(NOT_CONSTANT.to_string());
+        }
+        // only the selected arm has to be a constant
+        let value = if self.condition.evaluate_constant(emitter)? != 0 {
+            self.then_expression.evaluate_constant(emitter)?
+        } else {
+            self.else_expression.evaluate_constant(emitter)?
+        };
+        Ok(ctype.convert_constant(value, emitter))
+    }
+    pub fn emit(self, emitter: &mut Emitter) -> Value {
+        let ctype = self.get_type(emitter);
+        let then_type = self.then_expression.get_type(emitter).decay();
+        let else_type = self.else_expression.get_type(emitter).decay();
+        let condition = self.condition.emit(emitter);
+        let condition = emitter.emit_condition(condition);
+        let function = emitter.get_function();
+        let then_block = emitter.context.append_basic_block(&function, "then");
+        let else_block = emitter.context.append_basic_block(&function, "else");
+        let end_block = emitter.context.append_basic_block(&function, "conditional");
+        emitter
+            .builder
+            .build_conditional_branch(condition, &then_block, &else_block);
+
+        // only the selected operand is evaluated, and each one is brought to the
+        // common type at the end of its own block
+        emitter.builder.position_at_end(&then_block);
+        let then_value = self.then_expression.emit(emitter);
+        let then_value = emitter.emit_conversion(then_value, &then_type, &ctype);
+        let then_block = emitter.builder.get_insert_block().expect("block");
+        emitter.builder.build_unconditional_branch(&end_block);
+        emitter.builder.position_at_end(&else_block);
+        let else_value = self.else_expression.emit(emitter);
+        let else_value = emitter.emit_conversion(else_value, &else_type, &ctype);
+        let else_block = emitter.builder.get_insert_block().expect("block");
+        emitter.builder.build_unconditional_branch(&end_block);
+
+        emitter.builder.position_at_end(&end_block);
+        let (then_value, else_value): (BasicValueEnum, BasicValueEnum) =
+            match (then_value, else_value) {
+                (Value::Int(then_value), Value::Int(else_value)) => {
+                    (then_value.into(), else_value.into())
+                }
+                (Value::Float(then_value), Value::Float(else_value)) => {
+                    (then_value.into(), else_value.into())
+                }
+                (Value::Pointer(then_value), Value::Pointer(else_value)) => {
+                    (then_value.into(), else_value.into())
+                }
+                (Value::Null, Value::Null) => return Value::Null,
+                _ => panic!("error: incompatible operand types in conditional expression"),
+            };
+        let phi = emitter
+            .builder
+            .build_phi(then_value.get_type(), "conditional");
+        phi.add_incoming(&[(&then_value, &then_block), (&else_value, &else_block)]);
+        Value::from_basic_value(phi.as_basic_value())
+    }
+}
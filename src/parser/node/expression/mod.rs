@@ -1,25 +1,61 @@
 pub mod binary;
+pub mod conditional;
+pub mod precedence;
 pub mod unary;
 
+use inkwell::values::PointerValue;
+
 use crate::emitter::emitter::Emitter;
 use crate::emitter::environment::Value;
+use crate::emitter::types::CType;
 use crate::lexer::token::Tokens;
 use crate::parser::node::expression::binary::BinaryNode;
+use crate::parser::node::expression::conditional::ConditionalNode;
 use crate::parser::node::expression::unary::UnaryNode;
 
+pub const NOT_CONSTANT: &str = "error: expression is not an integer constant expression";
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum ExpressionNode {
     Unary(UnaryNode),
     Binary(BinaryNode),
+    Conditional(ConditionalNode),
 }
 impl ExpressionNode {
     pub fn new(tokens: &mut Tokens) -> ExpressionNode {
         BinaryNode::new(tokens)
     }
+    pub fn new_assignment(tokens: &mut Tokens) -> ExpressionNode {
+        BinaryNode::new_assignment(tokens)
+    }
+    pub fn get_type(&self, emitter: &Emitter) -> CType {
+        match self {
+            ExpressionNode::Unary(node) => node.get_type(emitter),
+            ExpressionNode::Binary(node) => node.get_type(emitter),
+            ExpressionNode::Conditional(node) => node.get_type(emitter),
+        }
+    }
+    // the value of an integer constant expression, computed without emitting
+    // any code
+    pub fn evaluate_constant(&self, emitter: &Emitter) -> Result<i64, String> {
+        match self {
+            ExpressionNode::Unary(node) => node.evaluate_constant(emitter),
+            ExpressionNode::Binary(node) => node.evaluate_constant(emitter),
+            ExpressionNode::Conditional(node) => node.evaluate_constant(emitter),
+        }
+    }
+    // emit the address of the object designated by this expression
+    pub fn emit_address(self, emitter: &mut Emitter) -> PointerValue {
+        match self {
+            ExpressionNode::Unary(node) => node.emit_address(emitter),
+            _ => panic!("error: expression is not assignable"),
+        }
+    }
     pub fn emit(self, emitter: &mut Emitter) -> Value {
         match self {
             ExpressionNode::Unary(node) => node.emit(emitter),
             ExpressionNode::Binary(node) => node.emit(emitter),
+            ExpressionNode::Conditional(node) => node.emit(emitter),
         }
     }
 }
@@ -0,0 +1,93 @@
+use crate::lexer::token::Token;
+
+// C operator precedence levels, from the loosest to the tightest binding.
+//
+//  1  ,                                         left
+//  2  = += -= *= /= %= &= |= ^= <<= >>=         right
+//  3  ?:                                        right
+//  4  ||                                        left
+//  5  &&                                        left
+//  6  |                                         left
+//  7  ^                                         left
+//  8  &                                         left
+//  9  == !=                                     left
+// 10  < <= > >=                                 left
+// 11  << >>                                     left
+// 12  + -                                       left
+// 13  * / %                                     left
+// 14  prefix ++ -- + - ! ~ * & sizeof (type)    right
+// 15  postfix ++ -- [] ()                       left
+pub const ASSIGNMENT: u32 = 2;
+pub const PREFIX: u32 = 14;
+pub const POSTFIX: u32 = 15;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Associativity {
+    Right,
+    Left,
+}
+
+fn infix_precedence(token: &Token) -> Option<(u32, Associativity)> {
+    let op = match token {
+        Token::Op(op) => op,
+        Token::Comma => return Some((1, Associativity::Left)),
+        _ => return None,
+    };
+    let property = match op.as_ref() {
+        "=" | "+=" | "-=" | "*=" | "/=" | "%=" | "&=" | "|=" | "^=" | "<<=" | ">>=" => {
+            (2, Associativity::Right)
+        }
+        "?" => (3, Associativity::Right),
+        "||" => (4, Associativity::Left),
+        "&&" => (5, Associativity::Left),
+        "|" => (6, Associativity::Left),
+        "^" => (7, Associativity::Left),
+        "&" => (8, Associativity::Left),
+        "==" | "!=" => (9, Associativity::Left),
+        "<" | "<=" | ">" | ">=" => (10, Associativity::Left),
+        "<<" | ">>" => (11, Associativity::Left),
+        "+" | "-" => (12, Associativity::Left),
+        "*" | "/" | "%" => (13, Associativity::Left),
+        _ => return None,
+    };
+    Some(property)
+}
+
+// Returns the (left, right) binding powers of an infix operator. An operator
+// whose left binding power is lower than the current minimum ends the operand;
+// the right binding power is the minimum used to parse its right operand.
+pub fn infix_binding_power(token: &Token) -> Option<(u32, u32)> {
+    match infix_precedence(token) {
+        Some((precedence, Associativity::Left)) => Some((precedence * 2, precedence * 2 + 1)),
+        Some((precedence, Associativity::Right)) => Some((precedence * 2 + 1, precedence * 2)),
+        None => None,
+    }
+}
+
+// the minimum binding power that admits every infix operator of `precedence`
+// and above
+pub fn min_binding_power(precedence: u32) -> u32 {
+    precedence * 2
+}
+
+pub fn prefix_precedence(token: &Token) -> Option<u32> {
+    match token {
+        Token::Op(op) => match op.as_ref() {
+            "++" | "--" | "+" | "-" | "!" | "~" | "*" | "&" => Some(PREFIX),
+            _ => None,
+        },
+        Token::Sizeof => Some(PREFIX),
+        _ => None,
+    }
+}
+
+pub fn postfix_precedence(token: &Token) -> Option<u32> {
+    match token {
+        Token::Op(op) => match op.as_ref() {
+            "++" | "--" => Some(POSTFIX),
+            _ => None,
+        },
+        Token::SquareS | Token::ParenS => Some(POSTFIX),
+        _ => None,
+    }
+}
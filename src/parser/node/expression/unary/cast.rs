@@ -0,0 +1,40 @@
+use crate::emitter::emitter::Emitter;
+use crate::emitter::environment::Value;
+use crate::emitter::types::CType;
+use crate::lexer::token::Tokens;
+use crate::parser::node::declare::type_name::TypeNameNode;
+use crate::parser::node::expression::unary::UnaryNode;
+use crate::parser::node::expression::NOT_CONSTANT;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CastNode {
+    pub type_name: TypeNameNode,
+    pub val: Box<UnaryNode>,
+}
+impl CastNode {
+    pub fn new(tokens: &mut Tokens) -> CastNode {
+        let msg = "CastNode";
+        tokens.consume_paren_s().expect(msg);
+        let type_name = TypeNameNode::new(tokens);
+        tokens.consume_paren_e().expect(msg);
+        // a cast binds like a prefix operator
+        let val = Box::new(UnaryNode::new(tokens));
+        CastNode { type_name, val }
+    }
+    pub fn get_type(&self, emitter: &Emitter) -> CType {
+        self.type_name.get_type(emitter)
+    }
+    pub fn evaluate_constant(&self, emitter: &Emitter) -> Result<i64, String> {
+        let ctype = self.get_type(emitter);
+        if !ctype.is_integer() || !self.val.get_type(emitter).is_integer() {
+            return Err(NOT_CONSTANT.to_string());
+        }
+        Ok(ctype.convert_constant(self.val.evaluate_constant(emitter)?, emitter))
+    }
+    pub fn emit(self, emitter: &mut Emitter) -> Value {
+        let ctype = self.val.get_type(emitter).decay();
+        let type_name = self.get_type(emitter);
+        let val = self.val.emit(emitter);
+        emitter.emit_conversion(val, &ctype, &type_name)
+    }
+}
@@ -1,66 +1,174 @@
+pub mod cast;
 pub mod prefix;
 pub mod primary;
+pub mod sizeof;
 pub mod suffix;
 
+use inkwell::values::{BasicValueEnum, PointerValue};
+
 use crate::emitter::emitter::Emitter;
 use crate::emitter::environment::Value;
+use crate::emitter::types::CType;
 use crate::lexer::token::{Token, Tokens};
+use crate::parser::node::declare::type_name::TypeNameNode;
+use crate::parser::node::expression::precedence::{postfix_precedence, prefix_precedence};
+use crate::parser::node::expression::unary::cast::CastNode;
 use crate::parser::node::expression::unary::prefix::PrefixNode;
 use crate::parser::node::expression::unary::primary::PrimaryNode;
+use crate::parser::node::expression::unary::sizeof::SizeofNode;
 use crate::parser::node::expression::unary::suffix::{
-    ArrayAccessNode, FunctionCallNode, SuffixNode,
+    ArrayAccessNode, FunctionCallNode, IncDecNode, SuffixNode,
 };
+use crate::parser::node::expression::NOT_CONSTANT;
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum UnaryNode {
     Primary(PrimaryNode),
     Prefix(PrefixNode),
     Suffix(SuffixNode),
+    Sizeof(SizeofNode),
+    Cast(CastNode),
 }
 impl UnaryNode {
     pub fn new(tokens: &mut Tokens) -> UnaryNode {
+        if tokens.peek(0) == Some(Token::ParenS) && TypeNameNode::is_type_name(&tokens.peek(1)) {
+            return UnaryNode::Cast(CastNode::new(tokens));
+        }
         match tokens.peek(0) {
-            Some(token) => match token {
-                Token::Op(op, _) => match op.as_ref() {
-                    "*" | "&" => UnaryNode::new_with_prefix(tokens),
-                    _ => UnaryNode::new_with_suffix(tokens),
-                },
-                _ => UnaryNode::new_with_suffix(tokens),
+            Some(token) => match prefix_precedence(&token) {
+                Some(_) => UnaryNode::new_with_prefix(tokens),
+                None => UnaryNode::new_with_suffix(tokens),
             },
             None => UnaryNode::new_with_suffix(tokens),
         }
     }
     fn new_with_prefix(tokens: &mut Tokens) -> UnaryNode {
-        let (op, _property) = tokens
+        if let Some(Token::Sizeof) = tokens.peek(0) {
+            return UnaryNode::Sizeof(SizeofNode::new(tokens));
+        }
+        let op = tokens
             .consume_operator()
             .expect("UnaryNode, new_with_prefix");
-        match op.as_ref() {
-            "*" | "&" => UnaryNode::Prefix(PrefixNode {
-                op,
-                val: PrimaryNode::new(tokens),
-            }),
-            _ => panic!(),
-        }
+        // every prefix operator binds tighter than any infix one, so the
+        // operand is exactly the next unary expression
+        UnaryNode::Prefix(PrefixNode {
+            op,
+            val: Box::new(UnaryNode::new(tokens)),
+        })
     }
     fn new_with_suffix(tokens: &mut Tokens) -> UnaryNode {
-        match tokens.peek(1) {
-            Some(token) => match token {
+        let mut node = UnaryNode::Primary(PrimaryNode::new(tokens));
+        while let Some(token) = tokens.peek(0) {
+            if postfix_precedence(&token).is_none() {
+                break;
+            }
+            node = match token {
                 Token::SquareS => {
-                    UnaryNode::Suffix(SuffixNode::Array(ArrayAccessNode::new(tokens)))
+                    UnaryNode::Suffix(SuffixNode::Array(ArrayAccessNode::new(node, tokens)))
                 }
-                Token::ParenS => {
-                    UnaryNode::Suffix(SuffixNode::FunctionCall(FunctionCallNode::new(tokens)))
+                Token::ParenS => UnaryNode::Suffix(SuffixNode::FunctionCall(
+                    FunctionCallNode::new(node, tokens),
+                )),
+                _ => {
+                    let op = tokens
+                        .consume_operator()
+                        .expect("UnaryNode, new_with_suffix");
+                    UnaryNode::Suffix(SuffixNode::IncDec(IncDecNode {
+                        op,
+                        val: Box::new(node),
+                    }))
                 }
-                _ => UnaryNode::Primary(PrimaryNode::new(tokens)),
-            },
-            None => UnaryNode::Primary(PrimaryNode::new(tokens)),
+            };
         }
+        node
     }
     pub fn emit(self, emitter: &mut Emitter) -> Value {
+        if let CType::Array(_, _) = self.get_type(emitter) {
+            // an array used as a value decays to a pointer to its first element
+            let array_pointer = self.emit_address(emitter);
+            let const_zero = emitter.context.i32_type().const_int(0, false);
+            return Value::Pointer(unsafe {
+                emitter
+                    .builder
+                    .build_gep(array_pointer, &[const_zero, const_zero], "decay")
+            });
+        }
         match self {
             UnaryNode::Primary(node) => node.emit(emitter),
             UnaryNode::Prefix(node) => node.emit(emitter),
             UnaryNode::Suffix(node) => node.emit(emitter),
+            UnaryNode::Sizeof(node) => node.emit(emitter),
+            UnaryNode::Cast(node) => node.emit(emitter),
+        }
+    }
+    pub fn get_type(&self, emitter: &Emitter) -> CType {
+        match self {
+            UnaryNode::Primary(node) => node.get_type(emitter),
+            UnaryNode::Prefix(node) => node.get_type(emitter),
+            UnaryNode::Suffix(node) => node.get_type(emitter),
+            UnaryNode::Sizeof(node) => node.get_type(),
+            UnaryNode::Cast(node) => node.get_type(emitter),
+        }
+    }
+    pub fn evaluate_constant(&self, emitter: &Emitter) -> Result<i64, String> {
+        match self {
+            UnaryNode::Primary(node) => node.evaluate_constant(emitter),
+            UnaryNode::Prefix(node) => node.evaluate_constant(emitter),
+            UnaryNode::Sizeof(node) => Ok(node.get_size(emitter) as i64),
+            UnaryNode::Cast(node) => node.evaluate_constant(emitter),
+            UnaryNode::Suffix(_) => Err(NOT_CONSTANT.to_string()),
+        }
+    }
+    // emit the address of the object designated by this node; only lvalues
+    // have one
+    pub fn emit_address(self, emitter: &mut Emitter) -> PointerValue {
+        match self {
+            UnaryNode::Primary(node) => node.emit_address(emitter),
+            UnaryNode::Prefix(node) => node.emit_address(emitter),
+            UnaryNode::Suffix(SuffixNode::Array(node)) => node.emit_address(emitter),
+            _ => panic!("error: expression is not assignable"),
+        }
+    }
+}
+
+// add one to ("++") or subtract one from ("--") the object at `address`,
+// of type `ctype`, returning its values before and after the update
+pub fn emit_step(
+    address: PointerValue,
+    ctype: &CType,
+    op: &str,
+    emitter: &mut Emitter,
+) -> (Value, Value) {
+    let delta: i64 = match op {
+        "++" => 1,
+        "--" => -1,
+        _ => panic!(),
+    };
+    match emitter.builder.build_load(address, "old") {
+        BasicValueEnum::IntValue(old) => {
+            let delta = old.get_type().const_int(delta as u64, true);
+            let new = emitter.builder.build_int_add(old, delta, "new");
+            // a `_Bool` stays 0 or 1
+            let new = if *ctype == CType::BOOL {
+                emitter.emit_bool(Value::Int(new))
+            } else {
+                new
+            };
+            emitter.builder.build_store(address, new);
+            (Value::Int(old), Value::Int(new))
+        }
+        BasicValueEnum::FloatValue(old) => {
+            let delta = old.get_type().const_float(delta as f64);
+            let new = emitter.builder.build_float_add(old, delta, "new");
+            emitter.builder.build_store(address, new);
+            (Value::Float(old), Value::Float(new))
+        }
+        BasicValueEnum::PointerValue(old) => {
+            let delta = emitter.context.i32_type().const_int(delta as u64, true);
+            let new = unsafe { emitter.builder.build_gep(old, &[delta], "new") };
+            emitter.builder.build_store(address, new);
+            (Value::Pointer(old), Value::Pointer(new))
         }
+        _ => panic!("error: cannot increment value of this type"),
     }
 }
@@ -1,54 +1,98 @@
+use inkwell::values::PointerValue;
+
 use crate::emitter::emitter::Emitter;
-use crate::emitter::environment::{Value, Variable};
-use crate::parser::node::expression::unary::primary::PrimaryNode;
+use crate::emitter::environment::Value;
+use crate::emitter::types::CType;
+use crate::parser::node::expression::unary::{emit_step, UnaryNode};
+use crate::parser::node::expression::NOT_CONSTANT;
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct PrefixNode {
     pub op: String,
-    pub val: PrimaryNode,
+    pub val: Box<UnaryNode>,
 }
 impl PrefixNode {
+    pub fn get_type(&self, emitter: &Emitter) -> CType {
+        match self.op.as_ref() {
+            "*" => self.val.get_type(emitter).get_element_type(),
+            "&" => CType::Pointer(Box::new(self.val.get_type(emitter))),
+            "!" => CType::INT,
+            "~" | "-" | "+" => self.val.get_type(emitter).promote(),
+            _ => self.val.get_type(emitter),
+        }
+    }
+    pub fn evaluate_constant(&self, emitter: &Emitter) -> Result<i64, String> {
+        if !self.val.get_type(emitter).is_integer() {
+            return Err(NOT_CONSTANT.to_string());
+        }
+        let val = self.val.evaluate_constant(emitter)?;
+        let value = match self.op.as_ref() {
+            "~" => !val,
+            "!" => (val == 0) as i64,
+            "-" => val.wrapping_neg(),
+            "+" => val,
+            _ => return Err(NOT_CONSTANT.to_string()),
+        };
+        Ok(self.get_type(emitter).convert_constant(value, emitter))
+    }
     pub fn emit(self, emitter: &mut Emitter) -> Value {
         match self.op.as_ref() {
             "*" => {
-                let identifier = self.val.get_identifier();
-                let alloca = match emitter.environment.get(&identifier) {
-                    Some(variable) => match variable {
-                        Variable::Int(int_variable) => int_variable.pointer,
-                        _ => panic!(),
-                    },
-                    None => panic!(format!(
-                        "error: use of undeclared identifier \'{}\'",
-                        identifier
-                    )),
-                };
-                Value::Int(
-                    emitter
-                        .builder
-                        .build_load(alloca, &identifier)
-                        .into_int_value(),
-                )
+                let address = self.emit_address(emitter);
+                Value::from_basic_value(emitter.builder.build_load(address, "dereference"))
             } // dereference
-            "&" => {
-                let identifier = self.val.get_identifier();
-                let alloca = match emitter.environment.get(&identifier) {
-                    Some(variable) => match variable {
-                        Variable::Int(int_variable) => int_variable.pointer,
-                        _ => panic!(),
-                    },
-                    None => panic!(format!(
-                        "error: use of undeclared identifier \'{}\'",
-                        identifier
-                    )),
+            "&" => Value::Pointer(self.val.emit_address(emitter)), // reference
+            "~" => {
+                let val = match PrefixNode::emit_promoted(*self.val, emitter).get_int() {
+                    Ok(value) => value,
+                    Err(msg) => panic!("{}", msg),
                 };
-                Value::Int(
-                    emitter
-                        .builder
-                        .build_load(alloca, &identifier)
-                        .into_int_value(),
-                )
-            } // reference
+                Value::Int(emitter.builder.build_not(val, "not"))
+            } // bitwise not
+            "!" => {
+                let val = self.val.emit(emitter);
+                let condition = emitter.emit_condition(val);
+                let not = emitter.builder.build_not(condition, "not");
+                Value::Int(emitter.builder.build_int_z_extend(
+                    not,
+                    CType::INT.to_llvm_type(emitter).into_int_type(),
+                    "not",
+                ))
+            } // logical not
+            "-" => match PrefixNode::emit_promoted(*self.val, emitter) {
+                Value::Int(val) => Value::Int(emitter.builder.build_int_neg(val, "neg")),
+                Value::Float(val) => Value::Float(emitter.builder.build_float_neg(val, "neg")),
+                _ => panic!("error: invalid argument type to unary expression"),
+            }, // unary minus
+            "+" => match PrefixNode::emit_promoted(*self.val, emitter) {
+                Value::Pointer(_) | Value::Null => {
+                    panic!("error: invalid argument type to unary expression")
+                }
+                val => val,
+            }, // unary plus
+            "++" | "--" => {
+                let ctype = self.val.get_type(emitter);
+                let address = self.val.emit_address(emitter);
+                let (_old, new) = emit_step(address, &ctype, &self.op, emitter);
+                new
+            } // prefix increment / decrement
             _ => panic!(),
         }
     }
+    // the operand converted to the type of the result
+    fn emit_promoted(val: UnaryNode, emitter: &mut Emitter) -> Value {
+        let ctype = val.get_type(emitter);
+        let val = val.emit(emitter);
+        emitter.emit_conversion(val, &ctype, &ctype.promote())
+    }
+    // only `*p` designates an object: the one `p` points to
+    pub fn emit_address(self, emitter: &mut Emitter) -> PointerValue {
+        match self.op.as_ref() {
+            "*" => match self.val.emit(emitter).get_pointer() {
+                Ok(pointer) => pointer,
+                Err(_) => panic!("error: indirection requires pointer operand"),
+            },
+            _ => panic!("error: expression is not assignable"),
+        }
+    }
 }
@@ -1,49 +1,143 @@
+use inkwell::values::PointerValue;
+
 use crate::emitter::emitter::Emitter;
 use crate::emitter::environment::{Value, Variable};
+use crate::emitter::types::{CType, IntegerKind};
 use crate::lexer::token::{Token, Tokens};
+use crate::parser::node::expression::{ExpressionNode, NOT_CONSTANT};
 
 #[derive(Debug, PartialEq, Clone)]
-pub struct PrimaryNode {
-    pub token: Token,
+pub enum PrimaryNode {
+    Num(String),
+    Float(String),
+    Ide(String),
+    Paren(Box<ExpressionNode>),
 }
 impl PrimaryNode {
     pub fn new(tokens: &mut Tokens) -> PrimaryNode {
         match tokens.pop() {
             Some(token) => match token {
-                Token::Num(num_string) => PrimaryNode {
-                    token: Token::Num(num_string),
-                },
-                Token::Ide(ide_string) => PrimaryNode {
-                    token: Token::Ide(ide_string),
-                },
+                Token::Num(num_string) => PrimaryNode::Num(num_string),
+                Token::Float(float_string) => PrimaryNode::Float(float_string),
+                Token::Ide(ide_string) => PrimaryNode::Ide(ide_string),
+                Token::ParenS => {
+                    let expression = ExpressionNode::new(tokens);
+                    tokens.consume_paren_e().expect("PrimaryNode");
+                    PrimaryNode::Paren(Box::new(expression))
+                }
                 _ => panic!(),
             },
             None => panic!(),
         }
     }
     pub fn get_number_u64(&self) -> u64 {
-        match self.clone().token {
-            Token::Num(num) => num.parse::<u64>().expect(""),
+        match self {
+            PrimaryNode::Num(num) => num
+                .trim_end_matches(|c| "uUlL".contains(c))
+                .parse::<u64>()
+                .expect(""),
+            _ => panic!(),
+        }
+    }
+    // an integer constant has the first of `int` and `long` that can hold
+    // its value, or of their unsigned counterparts with a `u` suffix
+    fn get_number_type(&self) -> CType {
+        let num = match self {
+            PrimaryNode::Num(num) => num.to_lowercase(),
+            _ => panic!(),
+        };
+        let value = self.get_number_u64();
+        match (num.contains('u'), num.contains("ll"), num.contains('l')) {
+            (false, true, _) => CType::Integer {
+                kind: IntegerKind::LongLong,
+                signed: true,
+            },
+            (true, true, _) => CType::Integer {
+                kind: IntegerKind::LongLong,
+                signed: false,
+            },
+            (false, _, false) if value <= i32::MAX as u64 => CType::INT,
+            (true, _, false) if value <= u32::MAX as u64 => CType::UNSIGNED_INT,
+            (false, _, _) => CType::LONG,
+            (true, _, _) => CType::UNSIGNED_LONG,
+        }
+    }
+    pub fn get_number_f64(&self) -> f64 {
+        let num = match self {
+            PrimaryNode::Float(num) => num.trim_end_matches(|c| "fFlL".contains(c)),
             _ => panic!(),
+        };
+        if num.starts_with("0x") || num.starts_with("0X") {
+            return parse_hex_float(&num[2..]);
         }
+        num.parse::<f64>().expect("")
     }
     pub fn get_identifier(&self) -> String {
-        match self.clone().token {
-            Token::Ide(identifier) => identifier,
+        match self {
+            PrimaryNode::Ide(identifier) => identifier.clone(),
             _ => panic!(),
         }
     }
+    pub fn get_type(&self, emitter: &Emitter) -> CType {
+        match self {
+            PrimaryNode::Num(_) => self.get_number_type(),
+            // a floating constant is a `double` unless suffixed with `f`
+            PrimaryNode::Float(num) if num.ends_with(&['f', 'F'][..]) => CType::FLOAT,
+            PrimaryNode::Float(_) => CType::DOUBLE,
+            PrimaryNode::Ide(identifier) => match emitter.environment.get(identifier) {
+                Some(variable) => variable.get_type(),
+                None => panic!("error: use of undeclared identifier \'{}\'", identifier),
+            },
+            PrimaryNode::Paren(expression) => expression.get_type(emitter),
+        }
+    }
+    pub fn evaluate_constant(&self, emitter: &Emitter) -> Result<i64, String> {
+        match self {
+            PrimaryNode::Num(_) => Ok(self.get_number_u64() as i64),
+            PrimaryNode::Float(_) | PrimaryNode::Ide(_) => Err(NOT_CONSTANT.to_string()),
+            PrimaryNode::Paren(expression) => expression.evaluate_constant(emitter),
+        }
+    }
+    pub fn emit_address(self, emitter: &mut Emitter) -> PointerValue {
+        let identifier = match self {
+            PrimaryNode::Ide(identifier) => identifier,
+            PrimaryNode::Paren(expression) => return expression.emit_address(emitter),
+            _ => panic!("error: expression is not assignable"),
+        };
+        match emitter.environment.get(&identifier) {
+            Some(variable) => match variable {
+                Variable::Int(int_variable) => int_variable.pointer,
+                Variable::Float(float_variable) => float_variable.pointer,
+                Variable::Pointer(pointer_variable) => pointer_variable.pointer,
+                Variable::Array(array_variable) => array_variable.pointer,
+                Variable::Function(_) | Variable::Null => {
+                    panic!("error: \'{}\' is not assignable", identifier)
+                }
+            },
+            None => panic!("error: use of undeclared identifier \'{}\'", identifier),
+        }
+    }
     pub fn emit(self, emitter: &mut Emitter) -> Value {
-        match self.token {
-            Token::Num(_) => {
+        match self {
+            PrimaryNode::Num(_) => {
                 let num = self.get_number_u64();
-                Value::Int(emitter.context.i32_type().const_int(num, false))
+                let int_type = self.get_type(emitter).to_llvm_type(emitter).into_int_type();
+                Value::Int(int_type.const_int(num, false))
+            }
+            PrimaryNode::Float(_) => {
+                let float_type = self
+                    .get_type(emitter)
+                    .to_llvm_type(emitter)
+                    .into_float_type();
+                Value::Float(float_type.const_float(self.get_number_f64()))
             }
-            Token::Ide(_) => {
+            PrimaryNode::Ide(_) => {
                 let identifier = self.get_identifier();
                 let alloca = match emitter.environment.get(&identifier) {
                     Some(variable) => match variable {
                         Variable::Int(int_variable) => int_variable.pointer,
+                        Variable::Float(float_variable) => float_variable.pointer,
+                        Variable::Pointer(pointer_variable) => pointer_variable.pointer,
                         _ => panic!(),
                     },
                     None => panic!(format!(
@@ -51,14 +145,35 @@ impl PrimaryNode {
                         identifier
                     )),
                 };
-                Value::Int(
-                    emitter
-                        .builder
-                        .build_load(alloca, &identifier)
-                        .into_int_value(),
-                )
+                Value::from_basic_value(emitter.builder.build_load(alloca, &identifier))
             }
-            _ => panic!(),
+            PrimaryNode::Paren(expression) => expression.emit(emitter),
+        }
+    }
+}
+
+// the value of a hexadecimal floating constant without its `0x` prefix, as in
+// `1.8p3`: a hexadecimal significand scaled by a binary exponent
+fn parse_hex_float(num: &str) -> f64 {
+    let (significand, exponent) = match num.find(&['p', 'P'][..]) {
+        Some(index) => (&num[..index], &num[index + 1..]),
+        None => panic!("error: hexadecimal floating constant requires an exponent"),
+    };
+    let mut value = 0.0;
+    let mut scale = 1.0;
+    let mut fraction = false;
+    for c in significand.chars() {
+        if c == '.' {
+            fraction = true;
+            continue;
+        }
+        let digit = f64::from(c.to_digit(16).expect("hexadecimal digit"));
+        if fraction {
+            scale /= 16.0;
+            value += digit * scale;
+        } else {
+            value = value * 16.0 + digit;
         }
     }
+    value * 2f64.powi(exponent.parse::<i32>().expect("exponent"))
 }
@@ -0,0 +1,41 @@
+use crate::emitter::emitter::Emitter;
+use crate::emitter::environment::Value;
+use crate::emitter::types::CType;
+use crate::lexer::token::{Token, Tokens};
+use crate::parser::node::declare::type_name::TypeNameNode;
+use crate::parser::node::expression::unary::UnaryNode;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum SizeofNode {
+    Type(TypeNameNode),
+    Expression(Box<UnaryNode>),
+}
+impl SizeofNode {
+    pub fn new(tokens: &mut Tokens) -> SizeofNode {
+        let msg = "SizeofNode";
+        tokens.consume_sizeof().expect(msg);
+        if tokens.peek(0) == Some(Token::ParenS) && TypeNameNode::is_type_name(&tokens.peek(1)) {
+            tokens.consume_paren_s().expect(msg);
+            let type_name = TypeNameNode::new(tokens);
+            tokens.consume_paren_e().expect(msg);
+            return SizeofNode::Type(type_name);
+        }
+        SizeofNode::Expression(Box::new(UnaryNode::new(tokens)))
+    }
+    pub fn get_type(&self) -> CType {
+        CType::SIZE_T
+    }
+    // the operand only contributes its type and is never evaluated
+    pub fn get_size(&self, emitter: &Emitter) -> u64 {
+        let ctype = match self {
+            SizeofNode::Type(node) => node.get_type(emitter),
+            SizeofNode::Expression(node) => node.get_type(emitter),
+        };
+        ctype.size_of(emitter)
+    }
+    pub fn emit(self, emitter: &mut Emitter) -> Value {
+        let size = self.get_size(emitter);
+        let size_type = CType::SIZE_T.to_llvm_type(emitter).into_int_type();
+        Value::Int(size_type.const_int(size, false))
+    }
+}
@@ -1,105 +1,91 @@
 use inkwell::values::{BasicValueEnum, PointerValue};
 
 use crate::emitter::emitter::Emitter;
-use crate::emitter::environment::{Value, Variable};
+use crate::emitter::environment::{FunctionVariable, Value, Variable};
+use crate::emitter::types::CType;
 use crate::lexer::token::{Token, Tokens};
+use crate::parser::node::expression::unary::primary::PrimaryNode;
+use crate::parser::node::expression::unary::{emit_step, UnaryNode};
 use crate::parser::node::expression::ExpressionNode;
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum SuffixNode {
     Array(ArrayAccessNode),
     FunctionCall(FunctionCallNode),
+    IncDec(IncDecNode),
 }
 impl SuffixNode {
+    pub fn get_type(&self, emitter: &Emitter) -> CType {
+        match self {
+            SuffixNode::Array(node) => node.get_type(emitter),
+            SuffixNode::FunctionCall(node) => node.get_type(emitter),
+            SuffixNode::IncDec(node) => node.val.get_type(emitter),
+        }
+    }
     pub fn emit(self, emitter: &mut Emitter) -> Value {
         match self {
             SuffixNode::Array(node) => node.emit(emitter),
             SuffixNode::FunctionCall(node) => node.emit(emitter),
+            SuffixNode::IncDec(node) => node.emit(emitter),
         }
     }
 }
 #[derive(Debug, PartialEq, Clone)]
-pub struct ArrayElementNode {
-    pub identifier: String,
-    pub indexer_nodes: Vec<Box<ExpressionNode>>,
+pub struct ArrayAccessNode {
+    pub val: Box<UnaryNode>,
+    pub indexer: Box<ExpressionNode>,
 }
-impl ArrayElementNode {
-    pub fn new(tokens: &mut Tokens) -> ArrayElementNode {
-        let msg = "ArrayNode";
-        let identifier = tokens.consume_identifier().expect(msg);
-        let mut indexer_nodes = Vec::new();
-        while let Some(Token::SquareS) = tokens.peek(0) {
-            tokens.consume_square_s().expect(msg);
-            let indexer_node = Box::new(ExpressionNode::new(tokens));
-            indexer_nodes.push(indexer_node);
-            tokens.consume_square_e().expect(msg);
-        }
-        ArrayElementNode {
-            identifier,
-            indexer_nodes,
+impl ArrayAccessNode {
+    // `val` is the already parsed operand in front of the `[`
+    pub fn new(val: UnaryNode, tokens: &mut Tokens) -> ArrayAccessNode {
+        let msg = "ArrayAccessNode";
+        tokens.consume_square_s().expect(msg);
+        let indexer = ExpressionNode::new(tokens);
+        tokens.consume_square_e().expect(msg);
+        ArrayAccessNode {
+            val: Box::new(val),
+            indexer: Box::new(indexer),
         }
     }
-    pub fn emit_pointer(self, emitter: &mut Emitter) -> PointerValue {
-        let identifier = self.identifier;
-        let array_alloca = match emitter.environment.get(&identifier) {
-            Some(variable) => match variable {
-                Variable::Array(array_variable) => array_variable.pointer,
-                _ => panic!(),
-            },
-            None => panic!(format!(
-                "error: use of undeclared identifier \'{}\'",
-                identifier
-            )),
-        };
-        let const_zero = emitter.context.i32_type().const_int(0, false);
-
-        let mut indexer_nodes = self.indexer_nodes;
-        indexer_nodes.reverse();
-        let mut element_pointer = match indexer_nodes.pop() {
-            Some(indexer_node) => {
-                let indexer = match indexer_node.emit(emitter).get_int() {
-                    Ok(value) => value,
-                    Err(msg) => panic!(msg),
-                };
+    pub fn get_type(&self, emitter: &Emitter) -> CType {
+        self.val.get_type(emitter).get_element_type()
+    }
+    pub fn emit_address(self, emitter: &mut Emitter) -> PointerValue {
+        let ctype = self.val.get_type(emitter);
+        match ctype {
+            // an array is indexed in place
+            CType::Array(_, _) => {
+                let array_pointer = self.val.emit_address(emitter);
+                let indexer_type = self.indexer.get_type(emitter);
+                let indexer = self.indexer.emit(emitter);
+                let indexer = emitter.emit_index(indexer, &indexer_type);
+                let const_zero = emitter.context.i32_type().const_int(0, false);
                 unsafe {
                     emitter
                         .builder
-                        .build_gep(array_alloca, &[const_zero, indexer], "first_element")
+                        .build_gep(array_pointer, &[const_zero, indexer], "element")
                 }
             }
-            None => panic!(),
-        };
-        while let Some(indexer_node) = indexer_nodes.pop() {
-            let indexer = match indexer_node.emit(emitter).get_int() {
-                Ok(value) => value,
-                Err(msg) => panic!(msg),
-            };
-            element_pointer = unsafe {
-                emitter
-                    .builder
-                    .build_gep(element_pointer, &[const_zero, indexer], "element")
+            // `p[i]` is `*(p + i)`
+            CType::Pointer(_) => {
+                let pointer = match self.val.emit(emitter).get_pointer() {
+                    Ok(pointer) => pointer,
+                    Err(msg) => panic!("{}", msg),
+                };
+                let indexer_type = self.indexer.get_type(emitter);
+                let indexer = self.indexer.emit(emitter);
+                let indexer = emitter.emit_index(indexer, &indexer_type);
+                unsafe { emitter.builder.build_gep(pointer, &[indexer], "element") }
             }
+            _ => panic!("error: subscripted value is not an array or pointer"),
         }
-        element_pointer
-    }
-}
-
-#[derive(Debug, PartialEq, Clone)]
-pub struct ArrayAccessNode {
-    pub array_element: ArrayElementNode,
-}
-impl ArrayAccessNode {
-    pub fn new(tokens: &mut Tokens) -> ArrayAccessNode {
-        let array_element = ArrayElementNode::new(tokens);
-        ArrayAccessNode { array_element }
     }
     pub fn emit(self, emitter: &mut Emitter) -> Value {
-        let array_element_alloca = self.array_element.emit_pointer(emitter);
-        Value::Int(
+        let array_element_alloca = self.emit_address(emitter);
+        Value::from_basic_value(
             emitter
                 .builder
-                .build_load(array_element_alloca, "array_element")
-                .into_int_value(),
+                .build_load(array_element_alloca, "array_element"),
         )
     }
 }
@@ -110,20 +96,25 @@ pub struct FunctionCallNode {
     pub parameters: Vec<ExpressionNode>,
 }
 impl FunctionCallNode {
-    pub fn new(tokens: &mut Tokens) -> FunctionCallNode {
+    // `callee` is the already parsed operand in front of the `(`
+    pub fn new(callee: UnaryNode, tokens: &mut Tokens) -> FunctionCallNode {
         let msg = "FunctionCallNode";
-        let identifier = tokens.consume_identifier().expect(msg);
+        let identifier = match callee {
+            UnaryNode::Primary(PrimaryNode::Ide(identifier)) => identifier,
+            _ => panic!("error: called object is not a function"),
+        };
         tokens.consume_paren_s().expect(msg);
         let mut parameters = vec![];
-        while let Some(token) = tokens.peek(0) {
-            match token {
-                Token::ParenE => break,
-                _ => {
-                    let parameter = ExpressionNode::new(tokens);
-                    parameters.push(parameter);
-                    if let Some(Token::Comma) = tokens.peek(0) {
+        if tokens.peek(0) != Some(Token::ParenE) {
+            loop {
+                // `,` separates arguments here rather than acting as an operator
+                parameters.push(ExpressionNode::new_assignment(tokens));
+                match tokens.peek(0) {
+                    Some(Token::Comma) => {
                         tokens.pop();
                     }
+                    Some(Token::ParenE) => break,
+                    _ => panic!("error: expected ',' or ')' after argument"),
                 }
             }
         }
@@ -133,26 +124,133 @@ impl FunctionCallNode {
             parameters,
         }
     }
+    // the callee as declared so far, which need not be defined yet
+    fn get_function(&self, emitter: &Emitter) -> FunctionVariable {
+        match emitter.environment.get(&self.identifier) {
+            Some(Variable::Function(function_variable)) => function_variable,
+            Some(_) => panic!(
+                "error: called object \'{}\' is not a function",
+                self.identifier
+            ),
+            None => panic!(
+                "error: implicit declaration of function \'{}\'",
+                self.identifier
+            ),
+        }
+    }
+    // the return type and the parameter types of the callee
+    fn get_function_type(&self, emitter: &Emitter) -> (CType, Vec<CType>) {
+        match self.get_function(emitter).ctype {
+            CType::Function(return_type, parameters) => (*return_type, parameters),
+            _ => panic!(),
+        }
+    }
+    pub fn get_type(&self, emitter: &Emitter) -> CType {
+        self.get_function_type(emitter).0
+    }
+    // an argument is converted as if assigned to its parameter, so its type
+    // has to be one that can be assigned
+    fn check_argument(
+        argument: &ExpressionNode,
+        ctype: &CType,
+        parameter_type: &CType,
+        emitter: &Emitter,
+    ) {
+        let compatible = match (ctype, parameter_type) {
+            (CType::Void, _) => panic!("error: void value not ignored as it ought to be"),
+            (from, to) if from.is_arithmetic() && to.is_arithmetic() => true,
+            // any pointer converts to `_Bool`
+            (CType::Pointer(_), to) if *to == CType::BOOL => true,
+            (CType::Pointer(from), CType::Pointer(to)) => {
+                from == to || from.is_void() || to.is_void()
+            }
+            // the only integer that converts to a pointer is a null pointer
+            // constant
+            (from, CType::Pointer(_)) if from.is_integer() => {
+                argument.evaluate_constant(emitter) == Ok(0)
+            }
+            _ => false,
+        };
+        if !compatible {
+            panic!(
+                "error: passing \'{}\' to parameter of incompatible type \'{}\'",
+                ctype, parameter_type
+            );
+        }
+    }
     pub fn emit(self, emitter: &mut Emitter) -> Value {
-        let identifier = self.identifier;
-        let fn_value = match emitter.module.get_function(&identifier) {
-            Some(function) => function,
-            None => panic!(format!("undefined reference to {:?}", identifier)),
+        let identifier = self.identifier.clone();
+        let function_variable = self.get_function(emitter);
+        let (return_type, parameter_types) = self.get_function_type(emitter);
+        let argument_types: Vec<CType> = self
+            .parameters
+            .iter()
+            .map(|val| val.get_type(emitter).decay())
+            .collect();
+        let parameter_types = if function_variable.prototype {
+            if argument_types.len() != parameter_types.len() {
+                panic!(
+                    "error: too {} arguments to function call \'{}\', expected {}, have {}",
+                    if argument_types.len() > parameter_types.len() {
+                        "many"
+                    } else {
+                        "few"
+                    },
+                    identifier,
+                    parameter_types.len(),
+                    argument_types.len()
+                );
+            }
+            for ((val, ctype), parameter_type) in self
+                .parameters
+                .iter()
+                .zip(&argument_types)
+                .zip(&parameter_types)
+            {
+                FunctionCallNode::check_argument(val, ctype, parameter_type, emitter);
+            }
+            parameter_types
+        } else {
+            // without a prototype nothing is known about the parameters, so the
+            // arguments only go through the default argument promotions
+            argument_types
+                .iter()
+                .map(|ctype| ctype.promote_argument())
+                .collect()
         };
+        let function_type = CType::Function(Box::new(return_type), parameter_types.clone());
+        let fn_value = emitter.get_function_value(&identifier, &function_type);
         let parameters: Vec<BasicValueEnum> = self
             .parameters
             .into_iter()
-            .map(|val| val.emit(emitter))
-            .map(|val| val.get_int().unwrap())
-            .map(|val| val.into())
+            .zip(argument_types)
+            .zip(parameter_types)
+            .map(|((val, ctype), parameter_type)| {
+                // each argument is converted to the type of its parameter
+                let val = val.emit(emitter);
+                emitter.emit_conversion(val, &ctype, &parameter_type)
+            })
+            .map(|val| val.get_basic_value().unwrap())
             .collect();
         let func_call_site = emitter.builder.build_call(fn_value, &parameters, "call");
-        Value::Int(
-            func_call_site
-                .try_as_basic_value()
-                .left()
-                .unwrap()
-                .into_int_value(),
-        )
+        // a call to a `void` function has no value
+        match func_call_site.try_as_basic_value().left() {
+            Some(value) => Value::from_basic_value(value),
+            None => Value::Null,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct IncDecNode {
+    pub op: String,
+    pub val: Box<UnaryNode>,
+}
+impl IncDecNode {
+    pub fn emit(self, emitter: &mut Emitter) -> Value {
+        let ctype = self.val.get_type(emitter);
+        let address = self.val.emit_address(emitter);
+        let (old, _new) = emit_step(address, &ctype, &self.op, emitter);
+        old
     }
 }
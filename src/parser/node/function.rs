@@ -1,79 +1,137 @@
-use inkwell::types::BasicTypeEnum;
-
 use crate::emitter::emitter::Emitter;
-use crate::emitter::environment::{IntVariable, Variable};
+use crate::emitter::environment::Variable;
+use crate::emitter::types::CType;
 use crate::lexer::token::{Token, Tokens};
-use crate::parser::node::declare::DeclareNode;
+use crate::parser::node::declare::parameter::ParameterNode;
+use crate::parser::node::declare::type_name::TypeNameNode;
 use crate::parser::node::statement::StatementsNode;
 
+// the part of a function that its declarations and its definition share, as
+// in `int f(int a, char *s)`
 #[derive(Debug, PartialEq, Clone)]
-pub struct FunctionNode {
+pub struct FunctionDeclareNode {
+    pub return_type: CType,
     pub identifier: String,
-    pub arguments: Vec<DeclareNode>,
-    pub statements: StatementsNode,
+    pub parameters: Vec<ParameterNode>,
+    // `int f()` leaves the parameters unspecified, while `int f(void)`
+    // declares that there are none
+    pub prototype: bool,
 }
-impl FunctionNode {
-    pub fn new(tokens: &mut Tokens) -> FunctionNode {
-        let msg = "FunctionNode";
-        let _function_type = tokens.consume_type().expect("type");
+impl FunctionDeclareNode {
+    pub fn new(tokens: &mut Tokens) -> FunctionDeclareNode {
+        let msg = "FunctionDeclareNode";
+        let return_type = TypeNameNode::new(tokens).ctype;
         let identifier = tokens.consume_identifier().expect("identifier");
         tokens.consume_paren_s().expect(msg);
-        let mut arguments = vec![];
-        while let Some(Token::Type(_)) = tokens.peek(0) {
-            let argument = DeclareNode::new(tokens);
-            arguments.push(argument);
-            if let Some(Token::Comma) = tokens.peek(0) {
-                tokens.pop();
+        let prototype = tokens.peek(0) != Some(Token::ParenE);
+        // `(void)` declares that there are no parameters
+        if tokens.peek(0) == Some(Token::Type("void".to_string()))
+            && tokens.peek(1) == Some(Token::ParenE)
+        {
+            tokens.pop();
+        }
+        let mut parameters = vec![];
+        if tokens.peek(0) != Some(Token::ParenE) {
+            loop {
+                parameters.push(ParameterNode::new(tokens));
+                match tokens.peek(0) {
+                    Some(Token::Comma) => {
+                        tokens.pop();
+                    }
+                    Some(Token::ParenE) => break,
+                    _ => panic!("error: expected \',\' or \')\' after parameter"),
+                }
             }
         }
         tokens.consume_paren_e().expect(msg);
+        FunctionDeclareNode {
+            return_type,
+            identifier,
+            parameters,
+            prototype,
+        }
+    }
+    pub fn get_type(&self, emitter: &Emitter) -> CType {
+        let parameters: Vec<CType> = self
+            .parameters
+            .iter()
+            .map(|parameter| match parameter.get_type(emitter) {
+                CType::Void => panic!("error: parameter has incomplete type \'void\'"),
+                ctype => ctype,
+            })
+            .collect();
+        CType::Function(Box::new(self.return_type.clone()), parameters)
+    }
+    // a declaration without a body, as in `int f(int);`
+    pub fn emit(self, emitter: &mut Emitter) {
+        let ctype = self.get_type(emitter);
+        emitter.declare_function(&self.identifier, ctype, self.prototype, false);
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct FunctionNode {
+    pub declaration: FunctionDeclareNode,
+    pub statements: StatementsNode,
+}
+impl FunctionNode {
+    // the body that follows `declaration`
+    pub fn new(declaration: FunctionDeclareNode, tokens: &mut Tokens) -> FunctionNode {
+        let msg = "FunctionNode";
         tokens.consume_block_s().expect(msg);
         let statements = StatementsNode::new(tokens);
         tokens.consume_block_e().expect(msg);
         FunctionNode {
-            identifier,
-            arguments,
+            declaration,
             statements,
         }
     }
     pub fn emit(self, emitter: &mut Emitter) {
-        // prepare
-        let mut arguments = self.arguments;
-        arguments.reverse();
-
-        let parameters: Vec<BasicTypeEnum> = arguments
-            .iter()
-            .map(|_| emitter.context.i32_type().into())
-            .collect();
-        let function = emitter.module.add_function(
-            &self.identifier,
-            emitter.context.i32_type().fn_type(&parameters, false),
-            None,
-        );
+        let declaration = self.declaration;
+        let ctype = declaration.get_type(emitter);
+        // a definition fixes the parameters even when it lists none
+        emitter.declare_function(&declaration.identifier, ctype.clone(), true, true);
+        let function = emitter.get_function_value(&declaration.identifier, &ctype);
+        let (return_type, parameters) = match ctype {
+            CType::Function(return_type, parameters) => (*return_type, parameters),
+            _ => panic!(),
+        };
+        emitter.environment.push_scope();
+        emitter.return_type = Some(return_type.clone());
         let basic_block = emitter.context.append_basic_block(&function, "entry");
         emitter.builder.position_at_end(&basic_block);
 
-        for (i, parameter_declare) in arguments.into_iter().enumerate() {
+        for (i, (parameter, parameter_type)) in declaration
+            .parameters
+            .into_iter()
+            .zip(parameters)
+            .enumerate()
+        {
             let parameter_value = match function.get_nth_param(i as u32) {
-                Some(val) => val.into_int_value(),
+                Some(val) => val,
                 None => panic!(),
             };
-            let identifier = parameter_declare.get_identifier();
+            let identifier = match parameter.identifier {
+                Some(identifier) => identifier,
+                None => panic!("error: parameter name omitted"),
+            };
             let parameter_alloca = emitter
                 .builder
-                .build_alloca(emitter.context.i32_type(), &identifier);
+                .build_alloca(parameter_type.to_llvm_type(emitter), &identifier);
             emitter
                 .builder
                 .build_store(parameter_alloca, parameter_value);
-            let variable = Variable::Int(IntVariable {
-                name: identifier,
-                pointer: parameter_alloca,
-            });
-            emitter
-                .environment
-                .update(parameter_declare.get_identifier(), variable);
+            let variable = Variable::new(identifier.clone(), parameter_alloca, parameter_type);
+            emitter.environment.update(identifier, variable);
         }
 
         self.statements.emit(emitter);
+        // a `void` function may end without a `return`
+        let last_block = emitter.builder.get_insert_block().expect("block");
+        if last_block.get_terminator().is_none() && return_type.is_void() {
+            emitter.builder.build_return(None);
+        }
+        emitter.environment.pop_scope();
+        emitter.return_type = None;
     }
 }
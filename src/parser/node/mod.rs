@@ -5,7 +5,7 @@ pub mod statement;
 
 use crate::emitter::emitter::Emitter;
 use crate::lexer::token::{Token, Tokens};
-use crate::parser::node::function::FunctionNode;
+use crate::parser::node::function::{FunctionDeclareNode, FunctionNode};
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Node {
@@ -32,14 +32,23 @@ impl Node {
 #[derive(Debug, PartialEq, Clone)]
 pub enum TopLevelDeclareNode {
     Function(FunctionNode),
+    FunctionDeclare(FunctionDeclareNode),
 }
 impl TopLevelDeclareNode {
     fn new(tokens: &mut Tokens) -> TopLevelDeclareNode {
-        TopLevelDeclareNode::Function(FunctionNode::new(tokens))
+        let declaration = FunctionDeclareNode::new(tokens);
+        match tokens.peek(0) {
+            Some(Token::Semi) => {
+                tokens.consume_semi().expect("TopLevelDeclareNode");
+                TopLevelDeclareNode::FunctionDeclare(declaration)
+            }
+            _ => TopLevelDeclareNode::Function(FunctionNode::new(declaration, tokens)),
+        }
     }
     pub fn emit(self, emitter: &mut Emitter) {
         match self {
             TopLevelDeclareNode::Function(node) => node.emit(emitter),
+            TopLevelDeclareNode::FunctionDeclare(node) => node.emit(emitter),
         }
     }
 }
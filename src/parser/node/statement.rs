@@ -91,20 +91,42 @@ impl ExpressionStatementNode {
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct ReturnStatementNode {
-    pub expression: ExpressionNode,
+    // `None` for a bare `return;`
+    pub expression: Option<ExpressionNode>,
 }
 impl ReturnStatementNode {
     fn new(tokens: &mut Tokens) -> ReturnStatementNode {
         let msg = "ReturnStatementNode";
         tokens.consume_return().expect(msg);
-        let expression = ExpressionNode::new(tokens);
+        let expression = match tokens.peek(0) {
+            Some(Token::Semi) => None,
+            _ => Some(ExpressionNode::new(tokens)),
+        };
         tokens.consume_semi().expect(msg);
         ReturnStatementNode { expression }
     }
     pub fn emit(self, emitter: &mut Emitter) -> Value {
-        let ret = match self.expression.emit(emitter).get_int() {
+        let return_type = emitter
+            .return_type
+            .clone()
+            .expect("return outside a function");
+        let expression = match (self.expression, return_type.is_void()) {
+            (Some(expression), false) => expression,
+            (None, true) => {
+                emitter.builder.build_return(None);
+                return Value::Null;
+            }
+            (Some(_), true) => panic!("error: void function should not return a value"),
+            (None, false) => panic!("error: non-void function should return a value"),
+        };
+        let ctype = expression.get_type(emitter).decay();
+        let ret = expression.emit(emitter);
+        let ret = match emitter
+            .emit_conversion(ret, &ctype, &return_type)
+            .get_basic_value()
+        {
             Ok(value) => value,
-            Err(msg) => panic!(msg),
+            Err(msg) => panic!("{}", msg),
         };
         emitter.builder.build_return(Some(&ret));
         Value::Null
@@ -0,0 +1,11 @@
+use std::fs::File;
+use std::io::prelude::*;
+
+pub fn get_code(filename: &str) -> String {
+    let filename = String::from("./tests/resources/") + filename;
+    let mut f = File::open(filename).expect("file not found");
+    let mut contents = String::new();
+    f.read_to_string(&mut contents)
+        .expect("somethig went wrong reading the file");
+    contents
+}
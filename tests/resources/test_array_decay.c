@@ -0,0 +1,23 @@
+int first(int a[]) {
+    return a[0] + a[1] + a[2];
+}
+int grid(int m[2][3]) {
+    m[1][2] = 9;
+    return m[0][1];
+}
+int deref(int *p) {
+    return *p + p[1];
+}
+int main() {
+    int a[3];
+    int m[2][3];
+    a[0] = 1;
+    a[1] = 2;
+    a[2] = 3;
+    m[0][0] = 5;
+    m[0][1] = 4;
+    int *p = a;
+    int *q = a + 1;
+    int s = first(a) + grid(m) + deref(a) + deref(m[0]);
+    return s + m[1][2] + *q + p[2] + (q - a) + *(a + 2) + (a == p);
+}
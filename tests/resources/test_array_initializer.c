@@ -0,0 +1,20 @@
+int main() {
+    int a[3] = {1, 2, 3};
+    int m[2][3] = {{1, 2, 3}, {4, 5}};
+    int flat[2][2] = {1, 2, 3};
+    int z[4] = {7};
+    int inferred[] = {10, 20, 30, 40, 50,};
+    int rows[][2] = {{1, 2}, {3, 4}, {5}};
+    char s[] = "text";
+    char t[8] = "ab\n";
+    unsigned char u[] = "ok";
+    int sum = a[0] + a[1] + a[2];
+    sum = sum + m[0][2] + m[1][1] + m[1][2];
+    sum = sum + flat[1][0] + flat[1][1];
+    sum = sum + z[0] + z[3];
+    sum = sum + sizeof inferred / sizeof inferred[0] + inferred[4];
+    sum = sum + sizeof rows / sizeof rows[0] + rows[2][1];
+    sum = sum + sizeof s + (s[0] == 116) + (s[3] == 116) + s[4];
+    sum = sum + sizeof u + u[1];
+    return sum + t[2] + t[7] + sizeof t;
+}
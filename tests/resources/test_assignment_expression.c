@@ -0,0 +1,12 @@
+int id(int x) {
+    return x;
+}
+
+int main() {
+    int a;
+    int b;
+    a = b = 3;
+    int c = id(b = 4) + a;
+    int d;
+    return d = c + b;
+}
@@ -0,0 +1,11 @@
+int main() {
+    int a = 10 - 2 - 3;
+    int b = 100 / 10 / 5;
+    int c = 2 * 3 + 4;
+    int d = 1 + 2 * 3 - 4;
+    int e = 32 >> 2 >> 1;
+    int x;
+    int y;
+    x = y = 7;
+    return a + b + c + d + e + x + y - -1 - 1;
+}
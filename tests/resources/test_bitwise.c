@@ -0,0 +1,13 @@
+int main() {
+    int a = 12;
+    int b = 10;
+    int *p;
+    p = &a;
+    int c = a & b;
+    int d = a | b;
+    int e = a ^ b;
+    int f = 1 << 4;
+    int g = 64 >> 2;
+    int h = *p & 4;
+    return c + d + e + f + g + h + ~0 + 1;
+}
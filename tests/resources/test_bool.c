@@ -0,0 +1,20 @@
+#include <stdbool.h>
+
+int main() {
+    _Bool a = 256;
+    bool b = 0.5;
+    bool c = false;
+    int x = 5;
+    bool p = &x;
+    c++;
+    c++;
+    bool d = true;
+    d--;
+    d--;
+    int r = a + b + c + p;
+    r = r * 10 + d;
+    r = r + sizeof(bool) * 10;
+    r = r + (bool)-3 + (true == 1) + __bool_true_false_are_defined;
+    r = r + (a + a);
+    return r;
+}
@@ -0,0 +1,8 @@
+int main() {
+    int a = 7;
+    int b = (int) sizeof(int) - 5 < 0;
+    int c = (int)(sizeof(int) - 5);
+    int d = (a) + (int) a * 2;
+    int e = sizeof((int) sizeof(int));
+    return b + c + d + e + (int)(a > 3);
+}
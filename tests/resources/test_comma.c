@@ -0,0 +1,13 @@
+int add(int a, int b) {
+    return a + b;
+}
+
+int main() {
+    int a;
+    int b;
+    int c = (a = 1, b = 2, a + b);
+    int d = add((a++, a), 10);
+    int e;
+    e = 5, e += 1;
+    return c + d + e;
+}
@@ -0,0 +1,18 @@
+int main() {
+    int a = 10;
+    a += 5;
+    a -= 3;
+    a *= 2;
+    a /= 5;
+    a %= 3;
+    a <<= 4;
+    a >>= 1;
+    a |= 3;
+    a &= 14;
+    a ^= 5;
+    int b[2];
+    b[1] = 1;
+    b[1] += a;
+    int c = b[1] += 4;
+    return c + a;
+}
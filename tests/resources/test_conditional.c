@@ -0,0 +1,20 @@
+int max(int a, int b) {
+    return a > b ? a : b;
+}
+
+int classify(int x) {
+    return x == 1 ? 10 : x == 2 ? 20 : 30;
+}
+
+int main() {
+    int a = 0;
+    int b = 0;
+    int r = max(3, 9);
+    r += classify(2);
+    r += classify(5);
+    r += 1 ? a++ : b++;
+    r += 0 ? a++ : b++;
+    r += a * 10 + b;
+    r += (a ? 2 : 3) * 2;
+    return r;
+}
@@ -0,0 +1,9 @@
+int f();
+
+char f(int x) {
+    return x;
+}
+
+int main() {
+    return f(1);
+}
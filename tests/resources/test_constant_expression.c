@@ -0,0 +1,8 @@
+int main() {
+    int a[2 * 3];
+    int b[sizeof(int) * 4];
+    int c[(1 << 3) - 5 > 0 ? 7 : 1];
+    char d[sizeof a / sizeof a[0] + 1];
+    int e[(int)sizeof(int) - 1];
+    return sizeof a + sizeof b + sizeof c + sizeof d + sizeof e + sizeof(int[2 + 3]);
+}
@@ -0,0 +1,6 @@
+int main() {
+    char s[] = "\a\b\f\r\v\\\'\"\?\101\x42";
+    unsigned char u[] = "\377\x0";
+    int n = s[0] + s[1] + s[2] + s[3] + s[4] + s[9] + s[10] - s[5];
+    return sizeof(s) + n + s[6] + s[7] + s[8] + (u[0] == 255) + u[1] - 1;
+}
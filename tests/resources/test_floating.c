@@ -0,0 +1,18 @@
+int main() {
+    double d = 1.5;
+    float f = 0.25f;
+    double h = 0x1.8p3;
+    double e = 25e-1;
+    d = d * 4 + f;
+    int r = d;
+    r = r + h;
+    r = r + e * 2;
+    r = r + (f < d) + (d == 6.25) + (1 / 2.0 > 0);
+    r = r + 7 / 2.0 * 2;
+    f++;
+    r = r + f * 4;
+    r = r + sizeof f + sizeof(double) + sizeof 1.0f + sizeof .5;
+    double n = -d;
+    r = r + (n < 0) + (int)-2.7 + (unsigned)3.9;
+    return r;
+}
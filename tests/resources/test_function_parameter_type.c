@@ -0,0 +1,30 @@
+char first(char *s) {
+    return *s;
+}
+
+long widen(long x) {
+    return x * 4294967296;
+}
+
+double scale(double x) {
+    return x * 2.5;
+}
+
+unsigned char wrap(unsigned char c) {
+    return c + 1;
+}
+
+long *pick(long *p) {
+    return p + 1;
+}
+
+int answer(void) {
+    return 42;
+}
+
+int main() {
+    char s[] = "hi";
+    long a[2] = {7, 9};
+    long big = widen(3);
+    return first(s) + (big >> 32) + scale(2) + wrap(255) + *pick(a) + answer();
+}
@@ -0,0 +1,13 @@
+int *second(int *p) {
+    return p + 1;
+}
+
+char low(int x) {
+    return x;
+}
+
+int main() {
+    int a[3] = {1, 2, 3};
+    *second(a) = 20;
+    return *second(a) + low(300) + sizeof(low(1));
+}
@@ -0,0 +1,12 @@
+int main() {
+    int a = 5;
+    int b = a++;
+    int c = ++a;
+    int d = a--;
+    int e = --a;
+    int x[2];
+    x[1] = 10;
+    x[1]++;
+    ++x[1];
+    return a + b + c + d + e + x[1];
+}
@@ -0,0 +1,27 @@
+int main() {
+    unsigned char c = 250;
+    c = c + 10;
+    short s = 70000;
+    long l = 2147483647;
+    l = l + 1;
+    long long int ll = 1;
+    ll = ll << 40;
+    unsigned u = 0;
+    u = u - 1;
+    unsigned short us = 65535;
+    us++;
+    signed char sc = 200;
+    int r = c;
+    r = r + (s == 4464);
+    r = r + (l > 0);
+    r = r + (ll >> 38);
+    r = r + (u > 0);
+    r = r + (-1 < u);
+    r = r + (-1 < 1L);
+    r = r + (us == 0);
+    r = r + (sc < 0);
+    r = r + sizeof(short) + sizeof(long long) + sizeof(unsigned) + sizeof(long int);
+    r = r + sizeof 10L + sizeof 4294967295 + sizeof 10u;
+    r = r + (unsigned char)-1 / 5;
+    return r;
+}
@@ -0,0 +1,20 @@
+int main() {
+    int a = 0;
+    int r = 0;
+    r += 1 < 2;
+    r += 2 <= 2;
+    r += 3 > 4;
+    r += 4 >= 5;
+    r += 5 == 5;
+    r += 5 != 5;
+    r += -1 < 0;
+    r += 1 < 2 == 1;
+    r += !0;
+    r += !7;
+    r += 2 && 3;
+    r += 0 && a++;
+    r += 0 || 5;
+    r += 1 || a++;
+    r += 1 < 2 && 3 > 2 || 0;
+    return r * 10 + a;
+}
@@ -0,0 +1,21 @@
+int main() {
+    int a[2][3];
+    int b[4];
+    int x = 1;
+    int i = 1;
+    int j = 2;
+    int *p = &b[0];
+    int **pp = &p;
+    *(p + 1) = 3;
+    (*pp)[2] = 1;
+    *&b[3] = 4;
+    b[0] = 0;
+    int *e = &a[i][j];
+    *e = 5;
+    a[0][0] = 2;
+    (x) = x + 2;
+    a[i][j - 1] = 7;
+    a[1][1]++;
+    --(*pp)[1];
+    return a[1][2] * 10 + a[1][1] + b[1] + b[2] + b[3] + x + **pp + p[&b[1] - p];
+}
@@ -0,0 +1,22 @@
+int weigh();
+int apply();
+
+int twice(int x) {
+    return x * 2;
+}
+
+int main() {
+    char c = 3;
+    short s = 4;
+    float f = 2.5;
+    int a[2] = {10, 20};
+    return weigh(c, s, f) + apply(a, 2) + twice(1);
+}
+
+int weigh(int c, int s, double f) {
+    return c * 10 + s + f * 2;
+}
+
+int apply(int *a, int n) {
+    return a[0] + a[1] + n;
+}
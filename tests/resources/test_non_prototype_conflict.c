@@ -0,0 +1,9 @@
+int f();
+
+int main() {
+    return f(1);
+}
+
+int f(long x) {
+    return x;
+}
@@ -0,0 +1,20 @@
+int sub(int a, int b) {
+    return a - b;
+}
+
+int at(char *s, int i) {
+    return s[i];
+}
+
+double ratio(int n, double d) {
+    return n / d;
+}
+
+int pick(int a, int b, int c) {
+    return a * 100 + b * 10 + c;
+}
+
+int main() {
+    char s[] = "abc";
+    return sub(50, 8) + at(s, 1) - pick(1, 2, 3) + ratio(9, 2.0) * 2 + pick(0, 5, 7);
+}
@@ -0,0 +1,11 @@
+int func(int x) {
+    return x * 2;
+}
+
+int main() {
+    int a = (1 + 2) * 3;
+    int b = ((2 + 3) * (4 - 1));
+    int c = func((a - 1)) - (b - 10);
+    (a)++;
+    return (c) + (a);
+}
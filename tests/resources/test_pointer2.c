@@ -0,0 +1,14 @@
+int main() {
+    int a = 3;
+    int b = 20;
+    int *p = &a;
+    int **pp = &p;
+    int ***ppp = &pp;
+    *p = *p + 4;
+    **pp = **pp * 2;
+    ***ppp += 1;
+    *pp = &b;
+    *p = *p + a;
+    ++*p;
+    return a + b + *&a + sizeof pp;
+}
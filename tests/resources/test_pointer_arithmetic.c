@@ -0,0 +1,18 @@
+int main() {
+    int a[5];
+    a[0] = 1;
+    a[1] = 2;
+    a[2] = 3;
+    a[3] = 4;
+    a[4] = 5;
+    int *p = &a[0];
+    int *q = &a[4];
+    int *r = 1 + p;
+    int d = q - p;
+    int s = *(p + 2) + *r + p[3] + q[-1];
+    q -= 2;
+    p += 1;
+    int c = (p < q) + (q > p) * 2 + (p == &a[1]) * 4 + (p != q) * 8 + (q - 1 == p) * 16;
+    r++;
+    return d + s + c + *r + (int)(p - q);
+}
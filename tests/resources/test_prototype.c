@@ -0,0 +1,20 @@
+int is_even(int n);
+int twice(int);
+
+int is_odd(int n) {
+    return n == 0 ? 0 : is_even(n - 1);
+}
+
+int is_even(int n) {
+    return n == 0 ? 1 : is_odd(n - 1);
+}
+
+int twice(int x);
+
+int main() {
+    return twice(is_even(10) * 20 + is_odd(7));
+}
+
+int twice(int x) {
+    return x * 2;
+}
@@ -0,0 +1,21 @@
+int thrice();
+int thrice(int x);
+int half(int x);
+int half();
+int negate();
+
+int negate(int x) {
+    return -x;
+}
+
+int main() {
+    return thrice(12) + half(8) + negate(-4);
+}
+
+int thrice(int x) {
+    return x * 3;
+}
+
+int half(int x) {
+    return x / 2;
+}
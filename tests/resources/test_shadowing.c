@@ -0,0 +1,13 @@
+int a() {
+    return 3;
+}
+
+int b(int x) {
+    int a[2] = {x, 4};
+    return a[0] * a[1];
+}
+
+int main() {
+    int c = a();
+    return c + b(5);
+}
@@ -0,0 +1,8 @@
+int main() {
+    int a = -7 / 2;
+    int b = -7 % 2;
+    int c = a * b;
+    int d = 7 % -3;
+    int e = 0 - -10;
+    return c + e + +d;
+}
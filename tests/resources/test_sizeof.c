@@ -0,0 +1,11 @@
+int main() {
+    int a[10];
+    int m[2][3];
+    int x = 0;
+    int n = sizeof a / sizeof a[0];
+    int s = sizeof(int) + sizeof x;
+    int q = sizeof(int *) + sizeof(int **);
+    int r = sizeof m + sizeof m[1] + sizeof(int[4]);
+    int t = sizeof x++ + sizeof(x = 5);
+    return n + s + q + r + t + x;
+}
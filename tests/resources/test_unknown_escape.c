@@ -0,0 +1,4 @@
+int main() {
+    char s[] = "\q";
+    return s[0];
+}
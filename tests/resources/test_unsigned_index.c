@@ -0,0 +1,13 @@
+int main() {
+    char buf[400];
+    char *t = buf + 100;
+    char big[40010];
+    unsigned char c = 200;
+    unsigned short s = 40000;
+    char *end = t + c;
+    t[-56] = 1;
+    t[0] = 3;
+    t[200] = 7;
+    big[40000] = 20;
+    return t[c] + *(t + c) + *(c + t) + *(end - c) + big[s];
+}
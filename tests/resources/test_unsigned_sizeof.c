@@ -0,0 +1,5 @@
+int main() {
+    int *p;
+    int f[-1 < sizeof(int) ? 1 : 2];
+    return sizeof f + sizeof p + (sizeof(int) - 5 > 0) + (-1 < sizeof(int));
+}
@@ -0,0 +1,21 @@
+void set(int *p) {
+    *p = 42;
+}
+
+void bump(int *p) {
+    *p = *p + 1;
+    return;
+}
+
+void nothing() {
+}
+
+int main() {
+    int x = 0;
+    set(&x);
+    bump(&x);
+    nothing();
+    (void)x;
+    (void)nothing();
+    return x;
+}
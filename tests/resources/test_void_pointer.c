@@ -0,0 +1,12 @@
+int sum(void *p, int n) {
+    int *q = (int *)p;
+    return q[0] + q[n - 1];
+}
+
+int main() {
+    int a[3] = {10, 20, 30};
+    void *p = a;
+    void *r = (void *)a;
+    int *back = p;
+    return sum(a, 3) + sum(p, 2) + *back + (p == r) + sizeof(void *);
+}
@@ -0,0 +1,53 @@
+use std::process::Command;
+
+mod common;
+use common::get_code;
+
+fn compile_fail(input: &str, expect: &str) {
+    // compile; an error aborts the compiler with its message
+    let output = Command::new("./target/debug/rcc2")
+        .arg(input)
+        .output()
+        .expect("process failed to execute");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    println!("{:?} => {:?}", stderr, expect);
+    assert!(!output.status.success());
+    assert!(stderr.contains(expect));
+}
+
+#[test]
+fn test_conflicting_types() {
+    let code = get_code("test_conflicting_types.c");
+    compile_fail(&code, "error: conflicting types for 'f'")
+}
+
+#[test]
+fn test_missing_argument_comma() {
+    let code = get_code("test_missing_argument_comma.c");
+    compile_fail(&code, "error: expected ',' or ')' after argument")
+}
+
+#[test]
+fn test_missing_initializer_comma() {
+    let code = get_code("test_missing_initializer_comma.c");
+    compile_fail(&code, "error: expected ',' or '}' after initializer")
+}
+
+#[test]
+fn test_missing_parameter_comma() {
+    let code = get_code("test_missing_parameter_comma.c");
+    compile_fail(&code, "error: expected ',' or ')' after parameter")
+}
+
+#[test]
+fn test_non_prototype_conflict() {
+    let code = get_code("test_non_prototype_conflict.c");
+    compile_fail(&code, "error: conflicting types for 'f'")
+}
+
+#[test]
+fn test_unknown_escape() {
+    let code = get_code("test_unknown_escape.c");
+    compile_fail(&code, "error: unknown escape sequence '\\q'")
+}
@@ -1,12 +1,12 @@
-use std::fs::File;
-use std::io::prelude::*;
 use std::process::Command;
 
+mod common;
+use common::get_code;
+
 fn run(input: &str, expect: &str) {
-    // compile
-    Command::new("sh")
-        .arg("-c")
-        .arg(format!("./target/debug/rcc2 \"{}\"", input))
+    // compile; the code is passed as is, so string literals keep their quotes
+    Command::new("./target/debug/rcc2")
+        .arg(input)
         .status()
         .expect("process failed to execute");
 
@@ -21,15 +21,6 @@ fn run(input: &str, expect: &str) {
     assert!(status.to_string() == String::from(format!("exit code: {}", expect)));
 }
 
-fn get_code(filename: &str) -> String {
-    let filename = String::from("./tests/resources/") + filename;
-    let mut f = File::open(filename).expect("file not found");
-    let mut contents = String::new();
-    f.read_to_string(&mut contents)
-        .expect("somethig went wrong reading the file");
-    contents
-}
-
 #[test]
 fn test_addition() {
     let code = get_code("test_addition.c");
@@ -54,12 +45,90 @@ fn test_array3() {
     run(&code, "72")
 }
 
+#[test]
+fn test_array_decay() {
+    let code = get_code("test_array_decay.c");
+    run(&code, "41")
+}
+
+#[test]
+fn test_array_initializer() {
+    let code = get_code("test_array_initializer.c");
+    run(&code, "217")
+}
+
+#[test]
+fn test_assignment_expression() {
+    let code = get_code("test_assignment_expression.c");
+    run(&code, "11")
+}
+
+#[test]
+fn test_associativity() {
+    let code = get_code("test_associativity.c");
+    run(&code, "38")
+}
+
+#[test]
+fn test_bitwise() {
+    let code = get_code("test_bitwise.c");
+    run(&code, "64")
+}
+
+#[test]
+fn test_bool() {
+    let code = get_code("test_bool.c");
+    run(&code, "56")
+}
+
+#[test]
+fn test_cast() {
+    let code = get_code("test_cast.c");
+    run(&code, "26")
+}
+
+#[test]
+fn test_comma() {
+    let code = get_code("test_comma.c");
+    run(&code, "21")
+}
+
+#[test]
+fn test_compound_assignment() {
+    let code = get_code("test_compound_assignment.c");
+    run(&code, "35")
+}
+
+#[test]
+fn test_conditional() {
+    let code = get_code("test_conditional.c");
+    run(&code, "74")
+}
+
+#[test]
+fn test_constant_expression() {
+    let code = get_code("test_constant_expression.c");
+    run(&code, "155")
+}
+
 #[test]
 fn test_division() {
     let code = get_code("test_division.c");
     run(&code, "6")
 }
 
+#[test]
+fn test_escape_sequence() {
+    let code = get_code("test_escape_sequence.c");
+    run(&code, "238")
+}
+
+#[test]
+fn test_floating() {
+    let code = get_code("test_floating.c");
+    run(&code, "64")
+}
+
 #[test]
 fn test_function_call() {
     let code = get_code("test_function_call.c");
@@ -72,6 +141,42 @@ fn test_function_call2() {
     run(&code, "35")
 }
 
+#[test]
+fn test_function_parameter_type() {
+    let code = get_code("test_function_parameter_type.c");
+    run(&code, "163")
+}
+
+#[test]
+fn test_function_type() {
+    let code = get_code("test_function_type.c");
+    run(&code, "65")
+}
+
+#[test]
+fn test_increment() {
+    let code = get_code("test_increment.c");
+    run(&code, "41")
+}
+
+#[test]
+fn test_integer_types() {
+    let code = get_code("test_integer_types.c");
+    run(&code, "107")
+}
+
+#[test]
+fn test_logical() {
+    let code = get_code("test_logical.c");
+    run(&code, "100")
+}
+
+#[test]
+fn test_lvalue() {
+    let code = get_code("test_lvalue.c");
+    run(&code, "70")
+}
+
 #[test]
 fn test_multi_statement() {
     let code = get_code("test_multi_statements.c");
@@ -90,20 +195,104 @@ fn test_multiplication() {
     run(&code, "2")
 }
 
+#[test]
+fn test_non_prototype_call() {
+    let code = get_code("test_non_prototype_call.c");
+    run(&code, "73")
+}
+
+#[test]
+fn test_parameter_order() {
+    let code = get_code("test_parameter_order.c");
+    run(&code, "83")
+}
+
+#[test]
+fn test_parenthesis() {
+    let code = get_code("test_parenthesis.c");
+    run(&code, "21")
+}
+
 #[test]
 fn test_pointer() {
     let code = get_code("test_pointer.c");
     run(&code, "24")
 }
 
+#[test]
+fn test_pointer2() {
+    let code = get_code("test_pointer2.c");
+    run(&code, "74")
+}
+
+#[test]
+fn test_pointer_arithmetic() {
+    let code = get_code("test_pointer_arithmetic.c");
+    run(&code, "50")
+}
+
+#[test]
+fn test_prototype() {
+    let code = get_code("test_prototype.c");
+    run(&code, "42")
+}
+
+#[test]
+fn test_prototype2() {
+    let code = get_code("test_prototype2.c");
+    run(&code, "44")
+}
+
+#[test]
+fn test_shadowing() {
+    let code = get_code("test_shadowing.c");
+    run(&code, "23")
+}
+
+#[test]
+fn test_signed_division() {
+    let code = get_code("test_signed_division.c");
+    run(&code, "14")
+}
+
+#[test]
+fn test_sizeof() {
+    let code = get_code("test_sizeof.c");
+    run(&code, "94")
+}
+
 #[test]
 fn test_subtraction() {
     let code = get_code("test_subtraction.c");
     run(&code, "5")
 }
 
+#[test]
+fn test_unsigned_index() {
+    let code = get_code("test_unsigned_index.c");
+    run(&code, "44")
+}
+
+#[test]
+fn test_unsigned_sizeof() {
+    let code = get_code("test_unsigned_sizeof.c");
+    run(&code, "17")
+}
+
 #[test]
 fn test_variable() {
     let code = get_code("test_variable.c");
     run(&code, "26")
 }
+
+#[test]
+fn test_void_function() {
+    let code = get_code("test_void_function.c");
+    run(&code, "43")
+}
+
+#[test]
+fn test_void_pointer() {
+    let code = get_code("test_void_pointer.c");
+    run(&code, "89")
+}